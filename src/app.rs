@@ -1,4 +1,4 @@
-use crossterm::event::{self, Event, KeyCode, KeyEvent};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
 use parversion::prelude::{ExecutionContext, ProgressEvent};
 use ratatui::{
     DefaultTerminal, Frame,
@@ -8,29 +8,50 @@ use ratatui::{
     style::Stylize,
     symbols::border,
     text::{Line, Span, Text},
-    widgets::{Block, Paragraph, Widget},
+    widgets::{Block, Gauge, Paragraph, Widget, Wrap},
 };
 use std::io;
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 
-use crate::constants::HOLD_TO_REGENERATE_SECONDS;
 use crate::constants::colors::{
     STATUS_BAR_INTERACTION_COLOR, STATUS_BAR_NAVIGATION_COLOR, STATUS_BAR_NAVIGATION_INPUT_COLOR,
 };
-use crate::content::ContentPayload;
+use crate::constants::{HOLD_TO_REGENERATE_SECONDS, SPINNER_FRAMES};
+use crate::content::validation::FieldProblem;
+use crate::content::{Content, ContentType, Translated};
 use crate::context::Context;
-use crate::loading_context::{LoadingContext, StageMessage};
+use crate::load_times::{average_load_time, record_load_time};
+use crate::loading_context::{LoadingContext, StageMessage, StageTiming};
 use crate::prelude::*;
+use crate::snapshots::list_snapshots;
 use crate::ui::UI;
+use crate::usage::{BudgetAction, record_usage, tokens_used_today};
+use crate::watch::Watch;
+
+enum Loaded {
+    Page(Result<Translated, Errors>),
+    /// A following page, with the URL of the page it extends.
+    NextPage(String, Result<Translated, Errors>),
+    /// A refresh of the watched page, with its URL.
+    WatchRefresh(String, Result<Translated, Errors>),
+    BudgetExceeded(u64),
+}
+
+struct Navigation {
+    url: String,
+    regenerate: bool,
+    task: JoinHandle<()>,
+}
 
 pub struct App {
     context: Context,
     ui: UI,
     exit: bool,
-    tx: mpsc::UnboundedSender<ContentPayload>,
-    rx: mpsc::UnboundedReceiver<ContentPayload>,
+    tx: mpsc::UnboundedSender<Loaded>,
+    rx: mpsc::UnboundedReceiver<Loaded>,
     double_tap_window: Duration,
     held_key: Option<KeyCode>,
     hold_start: Option<Instant>,
@@ -38,6 +59,17 @@ pub struct App {
     double_tap_pending: bool,
     regen_triggered: bool,
     loading_context: Option<Arc<RwLock<LoadingContext>>>,
+    navigation: Option<Navigation>,
+    notice: Option<String>,
+    pending_confirmation: Option<bool>,
+    loading_scroll: Option<u16>,
+    loading_max_scroll: u16,
+    watch: Option<Watch>,
+    error: Option<Errors>,
+    /// Entry highlighted in the error view's list of content types.
+    content_type_choice: Option<usize>,
+    /// Content type the current page was opened as without detecting it.
+    forced_content_type: Option<ContentType>,
 }
 
 impl App {
@@ -57,6 +89,15 @@ impl App {
             double_tap_pending: false,
             regen_triggered: false,
             loading_context: None,
+            navigation: None,
+            notice: None,
+            pending_confirmation: None,
+            loading_scroll: None,
+            loading_max_scroll: 0,
+            watch: None,
+            error: None,
+            content_type_choice: None,
+            forced_content_type: None,
         }
     }
 
@@ -65,10 +106,43 @@ impl App {
             terminal.draw(|frame| self.draw(frame))?;
             self.handle_events().await?;
 
-            if let Ok(content) = self.rx.try_recv() {
-                self.ui.run(content);
-                self.loading_context = None;
-                self.context.set_mode(Mode::Interaction);
+            if let Ok(loaded) = self.rx.try_recv() {
+                match loaded {
+                    Loaded::Page(Ok(translated)) => {
+                        if let (Some(navigation), Some(loading_context)) =
+                            (&self.navigation, &self.loading_context)
+                        {
+                            let elapsed = loading_context.read().unwrap().started_at.elapsed();
+                            record_load_time(&navigation.url, elapsed);
+                        }
+
+                        self.finish_navigation();
+                        self.forced_content_type = self.context.content_type_override();
+                        self.ui.run(translated.payload);
+                        self.notice_dropped_fields(&translated.problems);
+                        self.update_watch();
+                        self.context.set_mode(Mode::Interaction);
+                    }
+                    Loaded::Page(Err(e)) => {
+                        log::error!("Could not open page: {:?}", e);
+                        self.finish_navigation();
+                        self.error = Some(e);
+                        self.context.set_mode(Mode::Navigation);
+                    }
+                    Loaded::NextPage(origin, result) => {
+                        self.on_next_page(origin, result);
+                    }
+                    Loaded::WatchRefresh(url, result) => {
+                        self.on_watch_refresh(url, result);
+                    }
+                    Loaded::BudgetExceeded(tokens) => {
+                        if let Some(navigation) = &self.navigation {
+                            let regenerate = navigation.regenerate;
+                            self.finish_navigation();
+                            self.on_budget_exceeded(Some(tokens), regenerate);
+                        }
+                    }
+                }
             }
         }
         Ok(())
@@ -79,10 +153,12 @@ impl App {
     }
 
     async fn handle_events(&mut self) -> io::Result<()> {
-        if event::poll(Duration::from_millis(50))?
-            && let Event::Key(key_event) = event::read()?
-        {
-            self.handle_key_event(key_event).await;
+        if event::poll(Duration::from_millis(50))? {
+            match event::read()? {
+                Event::Key(key_event) => self.handle_key_event(key_event).await,
+                Event::Paste(text) => self.handle_paste_event(&text),
+                _ => {}
+            }
         }
 
         self.process_timers();
@@ -93,6 +169,17 @@ impl App {
         match key_event.code {
             KeyCode::Char('q') => self.exit(),
             KeyCode::Char('r') => self.on_special_key_press(KeyCode::Char('r')),
+            KeyCode::Char('w') => self.toggle_watch(),
+            KeyCode::Char('c') => self.clear_content_type(),
+            KeyCode::Char('j') | KeyCode::Down if self.error.is_some() => {
+                self.select_content_type(true);
+            }
+            KeyCode::Char('k') | KeyCode::Up if self.error.is_some() => {
+                self.select_content_type(false);
+            }
+            KeyCode::Enter if self.error.is_some() && self.content_type_choice.is_some() => {
+                self.force_content_type();
+            }
             KeyCode::Enter => self.navigate(false),
             _ => {
                 self.clear_key_state();
@@ -103,14 +190,36 @@ impl App {
     }
 
     fn handle_navigation_input_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key_event.modifiers.contains(KeyModifiers::ALT);
+        let input = self.context.input_mut();
+
         match key_event.code {
-            KeyCode::Char(c) => {
-                self.context.append_char(c);
-            }
-            KeyCode::Backspace => {
-                self.context.remove_last_char();
-            }
+            KeyCode::Char('a') if ctrl => input.move_home(),
+            KeyCode::Char('e') if ctrl => input.move_end(),
+            KeyCode::Char('b') if ctrl => input.move_left(),
+            KeyCode::Char('f') if ctrl => input.move_right(),
+            KeyCode::Char('b') if alt => input.move_word_left(),
+            KeyCode::Char('f') if alt => input.move_word_right(),
+            KeyCode::Char('u') if ctrl => input.delete_to_start(),
+            KeyCode::Char('k') if ctrl => input.delete_to_end(),
+            KeyCode::Char('w') if ctrl => input.delete_word_before(),
+            KeyCode::Char('d') if alt => input.delete_word_after(),
+            KeyCode::Char(c) if !ctrl && !alt => input.insert_char(c),
+            KeyCode::Left if ctrl || alt => input.move_word_left(),
+            KeyCode::Right if ctrl || alt => input.move_word_right(),
+            KeyCode::Left => input.move_left(),
+            KeyCode::Right => input.move_right(),
+            KeyCode::Home => input.move_home(),
+            KeyCode::End => input.move_end(),
+            KeyCode::Backspace if ctrl || alt => input.delete_word_before(),
+            KeyCode::Backspace => input.backspace(),
+            KeyCode::Delete => input.delete(),
+            KeyCode::Up => self.context.history_previous(),
+            KeyCode::Down => self.context.history_next(),
+            KeyCode::Tab => self.context.complete_input(),
             KeyCode::Enter => {
+                self.context.submit_input();
                 self.navigate(false);
             }
             _ => {}
@@ -119,6 +228,26 @@ impl App {
         None
     }
 
+    fn handle_paste_event(&mut self, text: &str) {
+        if let Mode::NavigationInput = self.context.get_mode() {
+            self.context.input_mut().insert_str(text.trim());
+        }
+    }
+
+    fn handle_confirmation_key_event(&mut self, key_event: KeyEvent, regenerate: bool) {
+        match key_event.code {
+            KeyCode::Char('y') => {
+                self.pending_confirmation = None;
+                self.start_navigation(regenerate, false);
+            }
+            KeyCode::Char('n') | KeyCode::Esc => {
+                self.pending_confirmation = None;
+                self.notice = Some("Navigation cancelled".to_string());
+            }
+            _ => {}
+        }
+    }
+
     fn handle_universal_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
         match key_event.code {
             KeyCode::Esc => {
@@ -127,6 +256,16 @@ impl App {
             KeyCode::Char('/') => {
                 self.context.set_mode(Mode::NavigationInput);
             }
+            KeyCode::PageUp if self.loading_context.is_some() => {
+                let current = self.loading_scroll.unwrap_or(self.loading_max_scroll);
+                self.loading_scroll = Some(current.saturating_sub(10));
+            }
+            KeyCode::PageDown if self.loading_context.is_some() => {
+                let next = self
+                    .loading_scroll
+                    .map(|current| current.saturating_add(10));
+                self.loading_scroll = next.filter(|next| *next < self.loading_max_scroll);
+            }
             _ => {}
         }
 
@@ -134,7 +273,16 @@ impl App {
     }
 
     async fn handle_key_event(&mut self, key_event: KeyEvent) {
-        self.handle_universal_key_event(key_event);
+        if let Some(regenerate) = self.pending_confirmation {
+            self.handle_confirmation_key_event(key_event, regenerate);
+            return;
+        }
+
+        let editing = matches!(self.context.get_mode(), Mode::Interaction) && self.ui.is_editing();
+
+        if !editing {
+            self.handle_universal_key_event(key_event);
+        }
 
         let action = {
             match self.context.get_mode().clone() {
@@ -177,6 +325,20 @@ impl App {
     }
 
     fn process_timers(&mut self) {
+        if self.navigation.is_none() && self.watch.as_ref().is_some_and(Watch::is_due) {
+            if self.budget_exhausted() {
+                self.watch = None;
+                self.notice = Some("Token budget reached, stopped watching".to_string());
+            } else if let Some(watch) = &mut self.watch {
+                watch.in_flight = true;
+                let url = watch.url.clone();
+                let watched_url = url.clone();
+                self.load_in_background(url, move |result| {
+                    Loaded::WatchRefresh(watched_url, result)
+                });
+            }
+        }
+
         if let Some(start) = self.hold_start
             && !self.regen_triggered
             && start.elapsed() >= Duration::from_secs(HOLD_TO_REGENERATE_SECONDS)
@@ -221,18 +383,346 @@ impl App {
             Action::OpenUsingRenderingEngine(url) => {
                 self.context.open_using_system(url);
             }
+            Action::PlayMedia(url) => {
+                if let Err(e) = self.context.play_media(&url) {
+                    log::error!("Could not play media: {:?}", e);
+                    self.notice = Some("Could not start the media player".to_string());
+                }
+            }
+            Action::Navigate(url) => {
+                self.context.follow_link(url);
+                self.navigate(false);
+            }
+            Action::NavigateAs(url, content_type) => {
+                self.context.follow_link_as(url, content_type);
+                self.navigate(false);
+            }
+            Action::LoadNextPage(_) if self.budget_exhausted() => {
+                self.notice = Some("Token budget reached, next page not loaded".to_string());
+                self.ui.next_page_failed();
+            }
+            Action::LoadNextPage(url) => {
+                let origin = self.context.url_to_string();
+                self.load_in_background(url, move |result| Loaded::NextPage(origin, result));
+            }
+            Action::ShowNotice(message) => {
+                self.notice = Some(message);
+            }
+            Action::ShowSnapshots => {
+                let snapshots = list_snapshots(&self.context.url_to_string());
+
+                if snapshots.is_empty() {
+                    self.notice = Some("No snapshots of this page yet".to_string());
+                } else {
+                    self.ui.show_snapshots(snapshots);
+                }
+            }
+        }
+    }
+
+    fn select_content_type(&mut self, forward: bool) {
+        let count = Content::all_content_types(self.context.get_config()).len();
+
+        self.content_type_choice = match (self.content_type_choice, forward) {
+            (None, _) => Some(0),
+            (Some(choice), true) => Some((choice + 1).min(count.saturating_sub(1))),
+            (Some(choice), false) => Some(choice.saturating_sub(1)),
+        };
+    }
+
+    /// Retries the failed page as the content type highlighted in the error
+    /// view, and remembers the choice for the page.
+    fn force_content_type(&mut self) {
+        let content_types = Content::all_content_types(self.context.get_config());
+
+        let Some(content_type) = self
+            .content_type_choice
+            .and_then(|choice| content_types.get(choice))
+        else {
+            return;
+        };
+
+        self.context.remember_content_type(content_type);
+        self.navigate(false);
+    }
+
+    /// Reloads the page with its content type detected again.
+    fn clear_content_type(&mut self) {
+        if self.context.forget_content_type() {
+            self.navigate(false);
+        } else if self.forced_content_type.is_some() {
+            self.notice = Some("The content type of this page is set in the config".to_string());
+        } else {
+            self.notice = Some("No content type is forced for this page".to_string());
+        }
+    }
+
+    fn toggle_watch(&mut self) {
+        if self.watch.take().is_some() {
+            self.notice = Some("Stopped watching".to_string());
+            return;
+        }
+
+        if !self.ui.can_merge() {
+            self.notice = Some("This page cannot be watched".to_string());
+            return;
+        }
+
+        if let Some(url) = self.context.get_url() {
+            let interval = self.context.get_config().watch.default_interval();
+            self.notice = Some(format!("Watching every {}s", interval.as_secs()));
+            self.watch = Some(Watch::new(url, interval));
+        }
+    }
+
+    /// Keeps watching a page that was reloaded, otherwise starts watching
+    /// the new page if it is configured to be watched and its view can take
+    /// in refreshes.
+    fn update_watch(&mut self) {
+        let url = self.context.url_to_string();
+
+        if let Some(watch) = &mut self.watch
+            && watch.url == url
+        {
+            watch.last_refresh = Instant::now();
+            watch.in_flight = false;
+            return;
+        }
+
+        self.watch = if self.ui.can_merge() {
+            self.context.get_config().watch.watch_for(&url)
+        } else {
+            None
+        };
+    }
+
+    /// Appends a following page unless the user has since left the page
+    /// that asked for it.
+    fn on_next_page(&mut self, origin: String, result: Result<Translated, Errors>) {
+        if origin != self.context.url_to_string() {
+            log::debug!("Dropping next page of {}", origin);
+            return;
+        }
+
+        match result {
+            Ok(translated) => {
+                self.ui.append(translated.payload);
+                self.notice_dropped_fields(&translated.problems);
+            }
+            Err(Errors::BudgetExceeded(tokens)) => {
+                self.notice = Some(format!(
+                    "Token budget reached after {} tokens, next page not loaded",
+                    tokens
+                ));
+                self.ui.next_page_failed();
+            }
+            Err(e) => {
+                log::error!("Could not load next page: {:?}", e);
+                self.ui.next_page_failed();
+            }
+        }
+    }
+
+    /// Tells which fields of a translation were dropped for not matching
+    /// the schema, so that missing values are not mistaken for missing
+    /// content.
+    fn notice_dropped_fields(&mut self, problems: &[FieldProblem]) {
+        let Some(first) = problems.first() else {
+            return;
+        };
+
+        self.notice = Some(match problems.len() {
+            1 => format!("Dropped an invalid field of the translation: {}", first),
+            count => format!(
+                "Dropped {} invalid fields of the translation, first {}",
+                count, first
+            ),
+        });
+    }
+
+    /// Merges a refresh of the watched page, dropping it if the user has
+    /// since moved to another page or stopped watching.
+    fn on_watch_refresh(&mut self, url: String, result: Result<Translated, Errors>) {
+        let Some(watch) = &mut self.watch else {
+            return;
+        };
+
+        if watch.url == url {
+            watch.last_refresh = Instant::now();
+            watch.in_flight = false;
+        }
+
+        if watch.url != url || url != self.context.url_to_string() {
+            log::debug!("Dropping refresh of {}", url);
+            return;
+        }
+
+        match result {
+            Ok(translated) => {
+                self.notice_dropped_fields(&translated.problems);
+                let added = self.ui.merge(translated.payload);
+
+                if added > 0 {
+                    let message = format!("{} new entries", added);
+                    self.context.get_config().watch.notify(&message);
+                    self.notice = Some(message);
+                }
+            }
+            Err(Errors::BudgetExceeded(tokens)) => {
+                self.watch = None;
+                self.notice = Some(format!(
+                    "Token budget reached after {} tokens, stopped watching",
+                    tokens
+                ));
+            }
+            Err(e) => {
+                log::error!("Could not refresh watched page: {:?}", e);
+            }
         }
     }
 
+    /// Translates `url` without showing the loading screen. The run is cut
+    /// short with `Errors::BudgetExceeded` once it uses up the remaining
+    /// budget, as the user cannot confirm going on from here.
+    fn load_in_background(
+        &mut self,
+        url: String,
+        into_loaded: impl FnOnce(Result<Translated, Errors>) -> Loaded + Send + 'static,
+    ) {
+        let mut context_clone = self.context.clone();
+        context_clone.set_url(url.clone());
+
+        let token_limit = self
+            .context
+            .get_config()
+            .budget
+            .remaining_tokens(tokens_used_today());
+
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+        let execution_context = ExecutionContext::with_progress(progress_tx);
+        let tx_clone = self.tx.clone();
+
+        tokio::spawn(async move {
+            let open = context_clone.open(execution_context, false);
+            tokio::pin!(open);
+
+            let mut page_tokens = 0;
+
+            let result = loop {
+                tokio::select! {
+                    result = &mut open => break result,
+                    Some(event) = progress_rx.recv() => {
+                        if let ProgressEvent::Event { tokens, .. } = event {
+                            page_tokens += tokens;
+                        }
+
+                        if token_limit.is_some_and(|limit| page_tokens >= limit) {
+                            break Err(Errors::BudgetExceeded(page_tokens));
+                        }
+                    }
+                }
+            };
+
+            while let Ok(event) = progress_rx.try_recv() {
+                if let ProgressEvent::Event { tokens, .. } = event {
+                    page_tokens += tokens;
+                }
+            }
+
+            record_usage(&url, page_tokens);
+            let _ = tx_clone.send(into_loaded(result));
+        });
+    }
+
+    /// Whether today's token budget is used up, checked before translating
+    /// anything in the background where the user cannot confirm.
+    fn budget_exhausted(&self) -> bool {
+        self.context
+            .get_config()
+            .budget
+            .remaining_tokens(tokens_used_today())
+            == Some(0)
+    }
+
+    /// Aborts or asks to go on once the budget is reached, `spent` being the
+    /// tokens the interrupted translation used, if it had started. Going on
+    /// translates the page again from the start.
+    fn on_budget_exceeded(&mut self, spent: Option<u64>, regenerate: bool) {
+        let message = match spent {
+            Some(tokens) => format!("Token budget reached after {} tokens", tokens),
+            None => "Token budget reached".to_string(),
+        };
+
+        match self.context.get_config().budget.on_exceeded {
+            BudgetAction::Abort => {
+                self.notice = Some(format!("{}, translation aborted", message));
+            }
+            BudgetAction::Confirm => {
+                let question = if spent.is_some() {
+                    "Restart the translation without the limit?"
+                } else {
+                    "Translate anyway?"
+                };
+
+                self.notice = Some(format!("{}. {} [y/n]", message, question));
+                self.pending_confirmation = Some(regenerate);
+            }
+        }
+    }
+
+    /// Records token usage for the current navigation and leaves the loading
+    /// screen.
+    fn finish_navigation(&mut self) {
+        if let Some(navigation) = self.navigation.take() {
+            navigation.task.abort();
+
+            if let Some(loading_context) = &self.loading_context {
+                let tokens = loading_context.read().unwrap().global_tokens;
+                record_usage(&navigation.url, tokens);
+            }
+        }
+
+        self.loading_context = None;
+    }
+
     fn navigate(&mut self, regenerate: bool) {
-        let loading_context = Arc::new(RwLock::new(LoadingContext::new()));
+        self.start_navigation(regenerate, true);
+    }
+
+    fn start_navigation(&mut self, regenerate: bool, enforce_budget: bool) {
+        self.finish_navigation();
+        self.notice = None;
+        self.error = None;
+        self.content_type_choice = None;
+        self.forced_content_type = None;
+
+        let token_limit = if enforce_budget {
+            self.context
+                .get_config()
+                .budget
+                .remaining_tokens(tokens_used_today())
+        } else {
+            None
+        };
+
+        if token_limit == Some(0) {
+            self.on_budget_exceeded(None, regenerate);
+            return;
+        }
+
+        let expected_duration = average_load_time(&self.context.url_to_string());
+        let loading_context = Arc::new(RwLock::new(LoadingContext::new(expected_duration)));
         self.loading_context = Some(Arc::clone(&loading_context));
+        self.loading_scroll = None;
 
         let (tx, mut rx) = mpsc::unbounded_channel();
         let execution_context = ExecutionContext::with_progress(tx);
 
         let loading_context_clone = Arc::clone(&loading_context);
+        let budget_tx = self.tx.clone();
         tokio::spawn(async move {
+            let mut budget_exceeded = false;
+
             while let Some(event) = rx.recv().await {
                 let mut loading_context = loading_context_clone.write().unwrap();
 
@@ -241,8 +731,15 @@ impl App {
                         loading_context
                             .stage_messages
                             .push((stage.to_string(), Vec::new()));
+                        loading_context
+                            .stage_timings
+                            .insert(stage.to_string(), StageTiming::new());
                     }
                     ProgressEvent::StageDone(stage) => {
+                        if let Some(timing) = loading_context.stage_timings.get_mut(stage) {
+                            timing.finished_at = Some(Instant::now());
+                        }
+
                         if let Some((_, messages)) = loading_context
                             .stage_messages
                             .iter_mut()
@@ -277,6 +774,14 @@ impl App {
                         loading_context.global_tokens += tokens;
                     }
                 }
+
+                if let Some(limit) = token_limit
+                    && !budget_exceeded
+                    && loading_context.global_tokens >= limit
+                {
+                    budget_exceeded = true;
+                    let _ = budget_tx.send(Loaded::BudgetExceeded(loading_context.global_tokens));
+                }
             }
         });
 
@@ -284,13 +789,18 @@ impl App {
         let execution_context_clone = execution_context.clone();
         let tx_clone = self.tx.clone();
 
-        tokio::spawn(async move {
-            let content_payload: ContentPayload = context_clone
+        let task = tokio::spawn(async move {
+            let result = context_clone
                 .open(execution_context_clone, regenerate)
-                .await
-                .expect("Could not open URL");
+                .await;
+
+            let _ = tx_clone.send(Loaded::Page(result));
+        });
 
-            tx_clone.send(content_payload).unwrap();
+        self.navigation = Some(Navigation {
+            url: self.context.url_to_string(),
+            regenerate,
+            task,
         });
     }
 }
@@ -302,15 +812,32 @@ impl App {
             .title(title.centered())
             .border_set(border::ROUNDED);
 
-        let url = self.context.url_to_string();
-
         let search_text = if let Mode::NavigationInput = self.context.get_mode() {
+            let input: Vec<char> = self.context.input().text().chars().collect();
+            let cursor = self.context.input().cursor();
+
+            let before: String = input[..cursor].iter().collect();
+            let under = input.get(cursor).map_or(' ', |ch| *ch).to_string();
+            let after: String = input.get(cursor + 1..).unwrap_or_default().iter().collect();
+
             Text::from(vec![Line::from(vec![
                 Span::raw("Navigate: ").white(),
-                Span::raw(url),
+                Span::raw(before),
+                Span::raw(under).reversed(),
+                Span::raw(after),
             ])])
         } else {
-            Text::from(vec![Line::from(vec![Span::raw(url)])])
+            let url = self.context.url_to_string();
+            let mut spans = vec![Span::raw(url)];
+
+            if let Some(content_type) = &self.forced_content_type {
+                spans.push(Span::styled(
+                    format!("  (as {}, c to detect)", content_type.as_str()),
+                    Style::default().fg(Color::Yellow),
+                ));
+            }
+
+            Text::from(vec![Line::from(spans)])
         };
 
         Paragraph::new(search_text)
@@ -321,23 +848,49 @@ impl App {
 
     fn render_body(&mut self, area: Rect, buf: &mut Buffer) {
         if let Some(loading_context) = &self.loading_context {
-            let mut lines: Vec<Line> = vec![Line::from(Span::styled(
-                "Loading page",
-                Style::default()
-                    .add_modifier(Modifier::BOLD)
-                    .fg(Color::Magenta),
-            ))];
-
             let guard = loading_context.read().unwrap();
+            let elapsed = guard.started_at.elapsed();
+
+            let mut lines: Vec<Line> = vec![Line::from(vec![
+                Span::styled(
+                    "Loading page",
+                    Style::default()
+                        .add_modifier(Modifier::BOLD)
+                        .fg(Color::Magenta),
+                ),
+                Span::styled(
+                    format!(" {:.1}s", elapsed.as_secs_f64()),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])];
+
+            let spinner =
+                SPINNER_FRAMES[(elapsed.as_millis() / 100) as usize % SPINNER_FRAMES.len()];
 
             for (stage_name, messages) in &guard.stage_messages {
                 // Stage heading
-                lines.push(Line::from(Span::styled(
-                    stage_name.clone(),
+                let timing = guard.stage_timings.get(stage_name);
+                let marker = match timing {
+                    Some(timing) if timing.is_active() => spinner,
+                    Some(_) => "✓",
+                    None => " ",
+                };
+
+                let mut heading = vec![Span::styled(
+                    format!("{} {}", marker, stage_name),
                     Style::default()
                         .add_modifier(Modifier::BOLD)
                         .fg(Color::Yellow),
-                )));
+                )];
+
+                if let Some(timing) = timing {
+                    heading.push(Span::styled(
+                        format!(" {:.1}s", timing.elapsed().as_secs_f64()),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+
+                lines.push(Line::from(heading));
 
                 // Stage messages
                 struct GroupedMessage<'a> {
@@ -397,7 +950,84 @@ impl App {
                     .fg(Color::Green),
             )));
 
-            Paragraph::new(lines).render(area, buf);
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Min(0), Constraint::Length(1)])
+                .split(area);
+
+            self.loading_max_scroll = (lines.len() as u16).saturating_sub(layout[0].height);
+            let scroll = self
+                .loading_scroll
+                .map_or(self.loading_max_scroll, |scroll| {
+                    scroll.min(self.loading_max_scroll)
+                });
+
+            Paragraph::new(lines)
+                .scroll((scroll, 0))
+                .render(layout[0], buf);
+
+            if let Some(expected) = guard.expected_duration {
+                let ratio = (elapsed.as_secs_f64() / expected.as_secs_f64().max(0.001)).min(1.0);
+
+                Gauge::default()
+                    .gauge_style(Style::default().fg(Color::Magenta))
+                    .ratio(ratio)
+                    .label(format!(
+                        "{:.1}s of {:.1}s average",
+                        elapsed.as_secs_f64(),
+                        expected.as_secs_f64()
+                    ))
+                    .render(layout[1], buf);
+            }
+        } else if let Some(error) = &self.error {
+            let mut lines = vec![Line::from(Span::styled(
+                "Could not open page",
+                Style::default().add_modifier(Modifier::BOLD).fg(Color::Red),
+            ))];
+
+            match error {
+                Errors::ValidationError(problems) => {
+                    lines.push(Line::from(
+                        "The translated content does not match the content type schema:",
+                    ));
+                    lines.extend(
+                        problems
+                            .iter()
+                            .map(|problem| Line::from(format!("  - {}", problem))),
+                    );
+                }
+                error => lines.push(Line::from(format!("{:?}", error))),
+            }
+
+            lines.extend([
+                Line::default(),
+                Line::from(Span::styled(
+                    "Choose a content type with j/k and press Enter to open the page as it:",
+                    Style::default().fg(Color::DarkGray),
+                )),
+            ]);
+
+            let list_start = lines.len();
+            let content_types = Content::all_content_types(self.context.get_config());
+
+            for (index, content_type) in content_types.iter().enumerate() {
+                if self.content_type_choice == Some(index) {
+                    lines.push(Line::from(Span::styled(
+                        format!("> {}", content_type.as_str()),
+                        Style::default().add_modifier(Modifier::REVERSED),
+                    )));
+                } else {
+                    lines.push(Line::from(format!("  {}", content_type.as_str())));
+                }
+            }
+
+            let selected_line = list_start + self.content_type_choice.unwrap_or(0);
+            let scroll = (selected_line + 1).saturating_sub(area.height as usize);
+
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .scroll((scroll as u16, 0))
+                .render(area, buf);
         } else {
             self.ui.render(area, buf);
         }
@@ -419,11 +1049,25 @@ impl App {
             }
         };
 
-        Paragraph::new(mode.as_str())
-            .style(style)
-            .render(layout[0], buf);
+        let mut status = vec![Span::styled(mode.as_str(), style)];
+
+        if let Some(notice) = &self.notice {
+            status.push(Span::raw("  "));
+            status.push(Span::styled(
+                notice.clone(),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+
+        Paragraph::new(Line::from(status)).render(layout[0], buf);
+
+        let mut version = format!("v{}", env!("CARGO_PKG_VERSION"));
+
+        if let Some(watch) = &self.watch {
+            version = format!("watching every {}s  {}", watch.interval.as_secs(), version);
+        }
 
-        Paragraph::new(format!("v{}", env!("CARGO_PKG_VERSION")))
+        Paragraph::new(version)
             .style(Style::default())
             .alignment(Alignment::Right)
             .render(layout[1], buf);
@@ -0,0 +1,340 @@
+use headless_chrome::protocol::cdp::Network::CookieParam;
+use headless_chrome::{Browser, Tab};
+use serde::Deserialize;
+use std::fs;
+use std::ops::Deref;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::cookies::load_cookies;
+use crate::fetch::RequestProfile;
+use crate::prelude::*;
+
+const DEFAULT_MAX_TABS: usize = 4;
+const PROFILE_DIR_NAME: &str = "chrome-profile";
+const PROFILE_LOCK_NAME: &str = "SingletonLock";
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct BrowserSettings {
+    /// Upper bound on the number of tabs open at once.
+    pub max_tabs: Option<usize>,
+    /// Keep a Chrome profile under the data directory so that logins
+    /// survive between sessions. Enabled unless set to `false`.
+    pub persistent_profile: Option<bool>,
+    /// Profile directory to use instead of the default one.
+    pub user_data_dir: Option<PathBuf>,
+    /// Netscape cookies.txt file imported into every tab.
+    pub cookies_file: Option<PathBuf>,
+}
+
+impl BrowserSettings {
+    pub fn profile_dir(&self) -> Option<PathBuf> {
+        if !self.persistent_profile.unwrap_or(true) {
+            return None;
+        }
+
+        self.user_data_dir
+            .clone()
+            .or_else(|| get_data_dir().map(|dir| dir.join(PROFILE_DIR_NAME)))
+    }
+
+    /// Process ID of a browser running on the profile directory. Chrome
+    /// keeps a `SingletonLock` link to `<hostname>-<pid>` in the profile
+    /// while it runs; a link left behind by a browser that was killed names
+    /// a process that is gone and is ignored.
+    pub fn profile_lock_holder(&self) -> Option<u32> {
+        let lock = fs::read_link(self.profile_dir()?.join(PROFILE_LOCK_NAME)).ok()?;
+        let (_, pid) = lock.to_str()?.rsplit_once('-')?;
+        let pid = pid.parse().ok()?;
+
+        is_process_running(pid).then_some(pid)
+    }
+}
+
+#[cfg(unix)]
+fn is_process_running(pid: u32) -> bool {
+    std::process::Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .stderr(std::process::Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+#[cfg(not(unix))]
+fn is_process_running(_pid: u32) -> bool {
+    false
+}
+
+type Launcher = dyn Fn() -> Result<Browser, Errors> + Send + Sync;
+
+/// Bounded set of browser tabs shared by all fetches. Tabs are blanked and
+/// kept for reuse after extraction together with the user agent and headers
+/// they were set up with, and the browser is relaunched if it stops
+/// responding.
+#[derive(Clone)]
+pub struct TabPool {
+    inner: Arc<PoolInner>,
+}
+
+struct PoolInner {
+    launch: Box<Launcher>,
+    max_tabs: usize,
+    cookies: Vec<CookieParam>,
+    state: Mutex<PoolState>,
+    slots: Arc<Semaphore>,
+}
+
+struct PoolState {
+    browser: Option<Browser>,
+    idle: Vec<(Arc<Tab>, RequestProfile)>,
+    in_use: usize,
+}
+
+impl TabPool {
+    pub fn new<F>(launch: F, settings: &BrowserSettings) -> Result<Self, Errors>
+    where
+        F: Fn() -> Result<Browser, Errors> + Send + Sync + 'static,
+    {
+        let browser = launch()?;
+        let max_tabs = settings.max_tabs.unwrap_or(DEFAULT_MAX_TABS).max(1);
+
+        Ok(TabPool {
+            inner: Arc::new(PoolInner {
+                launch: Box::new(launch),
+                max_tabs,
+                cookies: load_cookies(settings.cookies_file.as_deref()),
+                state: Mutex::new(PoolState {
+                    browser: Some(browser),
+                    idle: Vec::new(),
+                    in_use: 0,
+                }),
+                slots: Arc::new(Semaphore::new(max_tabs)),
+            }),
+        })
+    }
+
+    /// Waits, without blocking the runtime, until fewer than `max_tabs` tabs
+    /// are in use and reserves a slot for one more.
+    pub async fn reserve(&self) -> TabSlot {
+        if self.inner.slots.available_permits() == 0 {
+            log::debug!("All {} tabs are in use, waiting", self.inner.max_tabs);
+        }
+
+        let permit = Arc::clone(&self.inner.slots)
+            .acquire_owned()
+            .await
+            .expect("Tab pool semaphore is never closed");
+
+        TabSlot {
+            pool: self.clone(),
+            permit,
+        }
+    }
+
+    /// Hands out a tab for a request with `profile`. An idle tab already set
+    /// up with the same profile is preferred, then one still using the
+    /// defaults; otherwise a new tab is opened in place of an idle one.
+    fn acquire(
+        &self,
+        permit: OwnedSemaphorePermit,
+        profile: &RequestProfile,
+    ) -> Result<PooledTab, Errors> {
+        let mut state = self.inner.state.lock().unwrap();
+
+        let reusable = state
+            .idle
+            .iter()
+            .position(|(_, applied)| applied == profile)
+            .or_else(|| {
+                state
+                    .idle
+                    .iter()
+                    .position(|(_, applied)| applied.is_default())
+            });
+
+        let (tab, applied) = match reusable {
+            Some(index) if self.is_browser_alive(&state) => state.idle.remove(index),
+            _ => {
+                if state.in_use + state.idle.len() >= self.inner.max_tabs
+                    && let Some((tab, _)) = state.idle.pop()
+                    && let Err(e) = tab.close(true)
+                {
+                    log::debug!("Could not close tab: {}", e);
+                }
+
+                (self.open_tab(&mut state)?, RequestProfile::default())
+            }
+        };
+
+        state.in_use += 1;
+
+        Ok(PooledTab {
+            tab: Some(tab),
+            pool: self.clone(),
+            profile: applied,
+            discard: false,
+            _permit: permit,
+        })
+    }
+
+    fn open_tab(&self, state: &mut PoolState) -> Result<Arc<Tab>, Errors> {
+        if !self.is_browser_alive(state) {
+            self.restart(state)?;
+        }
+
+        let browser = state
+            .browser
+            .as_ref()
+            .ok_or_else(|| Errors::BrowserError("Browser is not running".into()))?;
+
+        let tab = match browser.new_tab() {
+            Ok(tab) => tab,
+            Err(e) => {
+                log::warn!("Could not create new tab, restarting browser: {}", e);
+                self.restart(state)?;
+
+                state
+                    .browser
+                    .as_ref()
+                    .ok_or_else(|| Errors::BrowserError("Browser is not running".into()))?
+                    .new_tab()
+                    .map_err(|e| Errors::BrowserError(format!("Could not create new tab: {}", e)))?
+            }
+        };
+
+        if !self.inner.cookies.is_empty()
+            && let Err(e) = tab.set_cookies(self.inner.cookies.clone())
+        {
+            log::warn!("Could not set cookies: {}", e);
+        }
+
+        Ok(tab)
+    }
+
+    fn is_browser_alive(&self, state: &PoolState) -> bool {
+        state
+            .browser
+            .as_ref()
+            .is_some_and(|browser| browser.get_version().is_ok())
+    }
+
+    fn restart(&self, state: &mut PoolState) -> Result<(), Errors> {
+        log::warn!("Restarting web browser");
+
+        state.idle.clear();
+        state.browser = None;
+        state.browser = Some((self.inner.launch)()?);
+
+        Ok(())
+    }
+
+    fn release(&self, tab: Arc<Tab>, profile: RequestProfile, discard: bool) {
+        let reusable = !discard && tab.navigate_to("about:blank").is_ok();
+
+        let mut state = self.inner.state.lock().unwrap();
+        state.in_use -= 1;
+
+        if reusable && state.in_use + state.idle.len() < self.inner.max_tabs {
+            state.idle.push((tab, profile));
+        } else if let Err(e) = tab.close(true) {
+            log::debug!("Could not close tab: {}", e);
+        }
+    }
+}
+
+/// A place in a [`TabPool`] reserved for one tab.
+pub struct TabSlot {
+    pool: TabPool,
+    permit: OwnedSemaphorePermit,
+}
+
+impl TabSlot {
+    /// Takes a tab for a request with `profile`. Opening a tab talks to the
+    /// browser synchronously, so this belongs on a blocking thread.
+    pub fn acquire(self, profile: &RequestProfile) -> Result<PooledTab, Errors> {
+        self.pool.acquire(self.permit, profile)
+    }
+}
+
+/// A tab checked out of a [`TabPool`], returned to it when dropped.
+pub struct PooledTab {
+    tab: Option<Arc<Tab>>,
+    pool: TabPool,
+    profile: RequestProfile,
+    discard: bool,
+    /// Held until the tab is back in the pool.
+    _permit: OwnedSemaphorePermit,
+}
+
+impl PooledTab {
+    /// Records the user agent and headers applied to the tab, so that it is
+    /// only reused for requests with the same ones.
+    pub fn set_request_profile(&mut self, profile: RequestProfile) {
+        self.profile = profile;
+    }
+
+    /// Closes the tab on release instead of keeping it for reuse, for tabs
+    /// that may still be busy.
+    pub fn discard_after_use(&mut self) {
+        self.discard = true;
+    }
+
+    /// Runs `operation` on a separate thread and gives up after `timeout`.
+    /// A tab that timed out may still be busy, so it is discarded.
+    pub fn run_with_timeout<T, F>(
+        &mut self,
+        timeout: Duration,
+        description: &str,
+        operation: F,
+    ) -> Result<T, Errors>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Tab) -> Result<T, Errors> + Send + 'static,
+    {
+        let tab = Arc::clone(self.tab.as_ref().expect("Tab already released"));
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let _ = tx.send(operation(&tab));
+        });
+
+        match rx.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                self.discard_after_use();
+                Err(Errors::Timeout(format!(
+                    "{} did not finish within {:?}",
+                    description, timeout
+                )))
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                self.discard_after_use();
+                Err(Errors::BrowserError(format!(
+                    "{} was interrupted",
+                    description
+                )))
+            }
+        }
+    }
+}
+
+impl Deref for PooledTab {
+    type Target = Tab;
+
+    fn deref(&self) -> &Tab {
+        self.tab.as_ref().expect("Tab already released")
+    }
+}
+
+impl Drop for PooledTab {
+    fn drop(&mut self) {
+        if let Some(tab) = self.tab.take() {
+            let profile = std::mem::take(&mut self.profile);
+            self.pool.release(tab, profile, self.discard);
+        }
+    }
+}
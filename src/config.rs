@@ -0,0 +1,73 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::sync::Arc;
+
+use crate::browser::BrowserSettings;
+use crate::content::custom::{CustomContentType, load_custom_content_types};
+use crate::fetch::FetchSettings;
+use crate::prelude::*;
+use crate::usage::BudgetSettings;
+use crate::watch::WatchSettings;
+
+const CONFIG_FILE_NAME: &str = "config.json";
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct Config {
+    pub bookmarks: Vec<String>,
+    /// Navigation keywords mapped to URL templates, where `{}` is replaced by
+    /// the rest of the input. Entries here take precedence over the defaults.
+    pub search_keywords: HashMap<String, String>,
+    pub browser: BrowserSettings,
+    pub budget: BudgetSettings,
+    pub watch: WatchSettings,
+    /// Content type names forced for URLs or domains, skipping detection.
+    pub content_types: HashMap<String, String>,
+    /// Content types defined in the `content_types` config directory.
+    #[serde(skip)]
+    pub custom_content_types: Vec<Arc<CustomContentType>>,
+    pub fetch: FetchSettings,
+    /// Fetch settings keyed by domain, applied on top of `fetch`. A key also
+    /// matches its subdomains.
+    pub fetch_overrides: HashMap<String, FetchSettings>,
+    /// Command used to play media, such as `mpv --no-terminal {}`. `{}` is
+    /// replaced by the media URL, which is appended when there is none.
+    pub media_player: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> Result<Self, Errors> {
+        let mut config = Config::read()?;
+        config.custom_content_types = load_custom_content_types();
+
+        Ok(config)
+    }
+
+    fn read() -> Result<Self, Errors> {
+        let Some(path) = get_config_dir().map(|dir| dir.join(CONFIG_FILE_NAME)) else {
+            log::warn!("Could not find config directory, using default config");
+            return Ok(Config::default());
+        };
+
+        if !path.exists() {
+            log::info!("No config found at {}, using defaults", path.display());
+            return Ok(Config::default());
+        }
+
+        log::debug!("config_path: {}", path.display());
+
+        let text = fs::read_to_string(&path)
+            .map_err(|e| Errors::ConfigError(format!("Could not read config: {}", e)))?;
+
+        serde_json::from_str(&text)
+            .map_err(|e| Errors::ConfigError(format!("Could not parse config: {}", e)))
+    }
+
+    pub fn fetch_settings_for(&self, url: &str) -> FetchSettings {
+        match find_domain_entry(&self.fetch_overrides, url) {
+            Some(overrides) => self.fetch.merge(overrides),
+            None => self.fetch.clone(),
+        }
+    }
+}
@@ -9,4 +9,21 @@ pub mod colors {
     pub const STATUS_BAR_NAVIGATION_INPUT_COLOR: Color = LIME.c300;
 }
 
+pub const PROGRAM_NAME: &str = "pori";
+
 pub const HOLD_TO_REGENERATE_SECONDS: u64 = 10;
+
+pub const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+pub const MAX_HISTORY_ENTRIES: usize = 500;
+
+pub const SEARCH_KEYWORD_PLACEHOLDER: &str = "{}";
+
+pub const DEFAULT_SEARCH_KEYWORDS: &[(&str, &str)] = &[
+    ("hn", "https://news.ycombinator.com"),
+    ("ddg", "https://duckduckgo.com/html/?q={}"),
+    (
+        "wiki",
+        "https://en.wikipedia.org/wiki/Special:Search?search={}",
+    ),
+];
@@ -0,0 +1,80 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::fs;
+use std::sync::Arc;
+
+use crate::prelude::*;
+
+const CONTENT_TYPES_DIR_NAME: &str = "content_types";
+
+/// A content type defined by the user in a JSON file in the config
+/// directory, holding its JSON Schema and the names that detect it.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct CustomContentType {
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    pub schema: Value,
+}
+
+/// Translated data for a user-defined content type.
+#[derive(Debug, Clone)]
+pub struct CustomContent {
+    pub content_type: Arc<CustomContentType>,
+    pub data: Value,
+}
+
+impl CustomContentType {
+    pub fn matches(&self, content_name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(content_name)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(content_name))
+    }
+}
+
+pub fn load_custom_content_types() -> Vec<Arc<CustomContentType>> {
+    let Some(dir) = get_config_dir().map(|dir| dir.join(CONTENT_TYPES_DIR_NAME)) else {
+        return Vec::new();
+    };
+
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+
+    let mut content_types: Vec<Arc<CustomContentType>> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .filter_map(|path| {
+            let parsed = fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|text| {
+                    serde_json::from_str::<CustomContentType>(&text).map_err(|e| e.to_string())
+                });
+
+            match parsed {
+                Ok(content_type) => {
+                    log::info!(
+                        "Loaded content type {} from {}",
+                        content_type.name,
+                        path.display()
+                    );
+                    Some(Arc::new(content_type))
+                }
+                Err(e) => {
+                    log::warn!("Could not load content type {}: {}", path.display(), e);
+                    None
+                }
+            }
+        })
+        .collect();
+
+    content_types.sort_by(|a, b| a.name.cmp(&b.name));
+
+    content_types
+}
@@ -1,7 +1,11 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{self, Value};
+use std::collections::HashSet;
 use std::error::Error;
 
+use crate::content::paginated::Paginated;
+use crate::prelude::*;
+
 pub const JSON_SCHEMA: &str = r#"
 {
   "$schema": "http://json-schema.org/draft-07/schema#",
@@ -64,15 +68,30 @@ pub const JSON_SCHEMA: &str = r#"
           }
         }
       }
+    },
+    "nextPageUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the next page of entries, if the page is paginated."
+    },
+    "previousPageUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the previous page of entries, if the page is paginated."
     }
   }
 }
 "#;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct Digest {
     pub title: Option<String>,
     pub entries: Vec<ContentItem>,
+    #[serde(default)]
+    pub next_page_url: Option<String>,
+    #[serde(default)]
+    pub previous_page_url: Option<String>,
 }
 
 impl Digest {
@@ -80,15 +99,146 @@ impl Digest {
         Digest {
             title: None,
             entries: Vec::new(),
+            next_page_url: None,
+            previous_page_url: None,
         }
     }
 
     pub fn get_json_schema() -> &'static str {
         JSON_SCHEMA
     }
+
+    /// Makes entry, author and page links absolute.
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        let resolve = |url: &mut Option<String>| {
+            *url = url.as_deref().map(|url| resolve_url(base_url, url));
+        };
+
+        for entry in &mut self.entries {
+            resolve(&mut entry.url);
+            resolve(&mut entry.discussion_url);
+
+            if let Some(author) = &mut entry.author {
+                resolve(&mut author.url);
+            }
+        }
+
+        resolve(&mut self.next_page_url);
+        resolve(&mut self.previous_page_url);
+    }
+
+    /// Replaces the entries of `first_page`, the first page as previously
+    /// fetched, with a freshly fetched one. Entries that dropped off the first
+    /// page are removed, while those loaded from following pages are kept
+    /// after it. Returns the identities of entries that were not present
+    /// before.
+    pub fn merge_latest(&mut self, latest: Digest, first_page: &Digest) -> HashSet<String> {
+        let known: HashSet<String> = self.entries.iter().map(ContentItem::identity).collect();
+        let latest_identities: HashSet<String> =
+            latest.entries.iter().map(ContentItem::identity).collect();
+        let first_page_identities: HashSet<String> = first_page
+            .entries
+            .iter()
+            .map(ContentItem::identity)
+            .collect();
+
+        let added: HashSet<String> = latest_identities.difference(&known).cloned().collect();
+
+        let previous = std::mem::replace(&mut self.entries, latest.entries);
+        self.entries.extend(previous.into_iter().filter(|entry| {
+            let identity = entry.identity();
+            !latest_identities.contains(&identity) && !first_page_identities.contains(&identity)
+        }));
+
+        self.title = latest.title.or(self.title.take());
+        if self.next_page_url.is_none() {
+            self.next_page_url = latest.next_page_url;
+        }
+
+        added
+    }
+}
+
+impl Paginated for Digest {
+    type Item = ContentItem;
+
+    fn items_mut(&mut self) -> &mut Vec<ContentItem> {
+        &mut self.entries
+    }
+
+    fn set_next_page_url(&mut self, next_page_url: Option<String>) {
+        self.next_page_url = next_page_url;
+    }
+
+    fn into_items(self) -> (Vec<ContentItem>, Option<String>) {
+        (self.entries, self.next_page_url)
+    }
+
+    fn item_key(item: &ContentItem) -> Option<String> {
+        Some(item.identity())
+    }
+}
+
+/// Differences between two versions of the same digest.
+#[derive(Clone, Debug, Default)]
+pub struct DigestDiff {
+    pub added: Vec<ContentItem>,
+    pub removed: Vec<ContentItem>,
+    pub changed: Vec<EntryChange>,
+}
+
+/// An entry present in both versions whose rank or score differs.
+#[derive(Clone, Debug)]
+pub struct EntryChange {
+    pub entry: ContentItem,
+    pub previous_rank: usize,
+    pub rank: usize,
+    pub previous_score: Option<String>,
+}
+
+impl Digest {
+    pub fn diff(&self, earlier: &Digest) -> DigestDiff {
+        let mut diff = DigestDiff::default();
+
+        for (index, entry) in self.entries.iter().enumerate() {
+            let identity = entry.identity();
+
+            match earlier
+                .entries
+                .iter()
+                .position(|previous| previous.identity() == identity)
+            {
+                Some(previous_index) => {
+                    let previous = &earlier.entries[previous_index];
+
+                    if previous_index != index || previous.score != entry.score {
+                        diff.changed.push(EntryChange {
+                            entry: entry.clone(),
+                            previous_rank: previous_index + 1,
+                            rank: index + 1,
+                            previous_score: previous.score.clone(),
+                        });
+                    }
+                }
+                None => diff.added.push(entry.clone()),
+            }
+        }
+
+        let identities: HashSet<String> = self.entries.iter().map(ContentItem::identity).collect();
+
+        diff.removed = earlier
+            .entries
+            .iter()
+            .filter(|entry| !identities.contains(&entry.identity()))
+            .cloned()
+            .collect();
+
+        diff
+    }
 }
 
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct ContentItem {
     pub title: Option<String>,
     pub content: Option<String>,
@@ -99,7 +249,17 @@ pub struct ContentItem {
     pub score: Option<String>,
 }
 
-#[derive(Deserialize, Clone, Debug)]
+impl ContentItem {
+    /// Key used to recognise the same entry across pages and refreshes.
+    pub fn identity(&self) -> String {
+        match &self.url {
+            Some(url) => format!("url:{}", url),
+            None => format!("title:{}", self.title.as_deref().unwrap_or_default()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Author {
     pub name: Option<String>,
     pub url: Option<String>,
@@ -116,3 +276,121 @@ pub fn deserialize_to_digest(json_data: &str) -> Result<Digest, Box<dyn Error>>
 
     serde_json::from_value(value).map_err(|e| e.into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+    {
+      "digest": {
+        "title": "Hacker News",
+        "entries": [
+          {
+            "title": "Show HN: A terminal browser",
+            "content": "Renders pages as structured views.",
+            "url": "https://example.com/pori",
+            "discussionUrl": "https://news.ycombinator.com/item?id=1",
+            "author": {
+              "name": "lieso",
+              "url": "https://news.ycombinator.com/user?id=lieso"
+            },
+            "timestamp": "2024-05-01T12:00:00Z",
+            "score": "128 points"
+          },
+          {
+            "title": "Ask HN: Untitled",
+            "discussionUrl": "item?id=2"
+          }
+        ],
+        "nextPageUrl": "https://news.ycombinator.com/?p=2"
+      }
+    }
+    "#;
+
+    #[test]
+    fn deserializes_camel_case_fields() {
+        let digest = deserialize_to_digest(SAMPLE).unwrap();
+
+        assert_eq!(digest.title.as_deref(), Some("Hacker News"));
+        assert_eq!(digest.entries.len(), 2);
+        assert_eq!(
+            digest.next_page_url.as_deref(),
+            Some("https://news.ycombinator.com/?p=2")
+        );
+        assert_eq!(digest.previous_page_url, None);
+
+        let entry = &digest.entries[0];
+        assert_eq!(
+            entry.discussion_url.as_deref(),
+            Some("https://news.ycombinator.com/item?id=1")
+        );
+        assert_eq!(entry.score.as_deref(), Some("128 points"));
+
+        let author = entry.author.as_ref().unwrap();
+        assert_eq!(author.name.as_deref(), Some("lieso"));
+        assert_eq!(
+            author.url.as_deref(),
+            Some("https://news.ycombinator.com/user?id=lieso")
+        );
+
+        let entry = &digest.entries[1];
+        assert_eq!(entry.discussion_url.as_deref(), Some("item?id=2"));
+        assert!(entry.author.is_none());
+    }
+
+    #[test]
+    fn round_trips_through_serialization() {
+        let digest = deserialize_to_digest(SAMPLE).unwrap();
+
+        let serialized = serde_json::to_string(&digest).unwrap();
+        assert!(serialized.contains("\"discussionUrl\""));
+        assert!(serialized.contains("\"nextPageUrl\""));
+
+        let reparsed = deserialize_to_digest(&serialized).unwrap();
+        assert_eq!(reparsed, digest);
+    }
+
+    fn page(urls: &[&str], next_page_url: Option<&str>) -> Digest {
+        serde_json::from_value(serde_json::json!({
+            "entries": urls.iter().map(|url| serde_json::json!({ "url": url })).collect::<Vec<_>>(),
+            "nextPageUrl": next_page_url,
+        }))
+        .unwrap()
+    }
+
+    fn urls(digest: &Digest) -> Vec<&str> {
+        digest
+            .entries
+            .iter()
+            .filter_map(|entry| entry.url.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn merges_the_latest_first_page_and_keeps_paged_in_entries() {
+        let first_page = page(&["a", "b", "c"], Some("?p=2"));
+        let mut digest = first_page.clone();
+        digest.append_page(page(&["d", "e"], Some("?p=3")));
+
+        let added = digest.merge_latest(page(&["x", "a", "b"], Some("?p=2")), &first_page);
+
+        assert_eq!(urls(&digest), ["x", "a", "b", "d", "e"]);
+        assert_eq!(added.len(), 1);
+        assert_eq!(digest.next_page_url.as_deref(), Some("?p=3"));
+    }
+
+    #[test]
+    fn does_not_grow_across_refreshes() {
+        let mut first_page = page(&["a", "b", "c"], None);
+        let mut digest = first_page.clone();
+
+        for latest in [&["x", "a", "b"][..], &["y", "x", "a"], &["z", "y", "x"]] {
+            let latest = page(latest, None);
+            digest.merge_latest(latest.clone(), &first_page);
+            first_page = latest;
+        }
+
+        assert_eq!(urls(&digest), ["z", "y", "x"]);
+    }
+}
@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "documentation",
+  "type": "object",
+  "description": "A documentation page is a technical reference or manual, such as API documentation, a user guide or a tutorial. Its content is organized under a hierarchy of headings and mixes prose with code examples. Documentation pages often link to related pages and to the previous and next pages of the manual.",
+  "properties": {
+    "title": {
+      "type": "string",
+      "description": "The title of the page."
+    },
+    "sections": {
+      "type": "array",
+      "description": "The sections of the page in document order. Nesting is expressed through the level of each heading rather than by nesting sections.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "heading": {
+            "type": "string",
+            "description": "The text of the section heading."
+          },
+          "level": {
+            "type": "integer",
+            "description": "The level of the heading, from 1 for the top level to 6."
+          },
+          "blocks": {
+            "type": "array",
+            "description": "The content of the section up to the next heading, in order.",
+            "items": {
+              "type": "object",
+              "properties": {
+                "kind": {
+                  "type": "string",
+                  "enum": ["paragraph", "code", "list", "note"],
+                  "description": "Whether the block is a paragraph of prose, a code block, a bulleted or numbered list, or a note, warning or other callout."
+                },
+                "text": {
+                  "type": "string",
+                  "description": "The text of a paragraph or note, or the exact source of a code block including line breaks and indentation."
+                },
+                "language": {
+                  "type": "string",
+                  "description": "The programming language of a code block, such as rust or shell."
+                },
+                "items": {
+                  "type": "array",
+                  "description": "The items of a list.",
+                  "items": {
+                    "type": "string"
+                  }
+                }
+              }
+            }
+          },
+          "links": {
+            "type": "array",
+            "description": "The links that appear in the text of the section.",
+            "items": {
+              "type": "object",
+              "properties": {
+                "text": {
+                  "type": "string",
+                  "description": "The text of the link as it appears in the section."
+                },
+                "url": {
+                  "type": "string",
+                  "format": "uri",
+                  "description": "The URL the link points to."
+                }
+              }
+            }
+          }
+        }
+      }
+    },
+    "previousPageUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the previous page of the documentation, if any."
+    },
+    "nextPageUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the next page of the documentation, if any."
+    }
+  }
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Documentation {
+    pub title: Option<String>,
+    #[serde(default)]
+    pub sections: Vec<Section>,
+    #[serde(default)]
+    pub previous_page_url: Option<String>,
+    #[serde(default)]
+    pub next_page_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Section {
+    pub heading: Option<String>,
+    pub level: Option<u8>,
+    #[serde(default)]
+    pub blocks: Vec<Block>,
+    #[serde(default)]
+    pub links: Vec<Link>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Block {
+    pub kind: Option<String>,
+    pub text: Option<String>,
+    pub language: Option<String>,
+    #[serde(default)]
+    pub items: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlockKind {
+    Paragraph,
+    Code,
+    List,
+    Note,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Link {
+    pub text: Option<String>,
+    pub url: Option<String>,
+}
+
+impl Documentation {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        let resolve = |url: &mut Option<String>| {
+            *url = url.as_deref().map(|url| resolve_url(base_url, url));
+        };
+
+        for link in self
+            .sections
+            .iter_mut()
+            .flat_map(|section| section.links.iter_mut())
+        {
+            resolve(&mut link.url);
+        }
+
+        resolve(&mut self.previous_page_url);
+        resolve(&mut self.next_page_url);
+    }
+}
+
+impl Section {
+    pub fn level(&self) -> u8 {
+        self.level.unwrap_or(1).clamp(1, 6)
+    }
+}
+
+impl Block {
+    /// Kind of the block, tolerating spellings other than those in the schema.
+    pub fn kind(&self) -> BlockKind {
+        let kind = self.kind.as_deref().unwrap_or_default().to_lowercase();
+
+        if kind.contains("code") || kind.contains("pre") {
+            BlockKind::Code
+        } else if kind.contains("list") || (kind.is_empty() && !self.items.is_empty()) {
+            BlockKind::List
+        } else if ["note", "warning", "tip", "callout", "caution", "important"]
+            .iter()
+            .any(|callout| kind.contains(callout))
+        {
+            BlockKind::Note
+        } else {
+            BlockKind::Paragraph
+        }
+    }
+}
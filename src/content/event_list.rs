@@ -0,0 +1,513 @@
+use chrono::{DateTime, Days, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::PROGRAM_NAME;
+use crate::content::paginated::Paginated;
+use crate::prelude::*;
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "event_list",
+  "type": "object",
+  "description": "An event listing is a page that announces upcoming or past events, such as a meetup group, a conference programme, a venue's calendar or a ticketing site. Each event has a title, a start time and usually an end time, a location or an indication that it takes place online, and a link to more details.",
+  "properties": {
+    "title": {
+      "type": "string",
+      "description": "The title of the page, such as the name of the group or venue."
+    },
+    "events": {
+      "type": "array",
+      "description": "The events listed on the page.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "title": {
+            "type": "string",
+            "description": "The name of the event."
+          },
+          "description": {
+            "type": "string",
+            "description": "A short description of the event."
+          },
+          "startTime": {
+            "type": "string",
+            "format": "date-time",
+            "description": "When the event starts, in ISO 8601 format such as 2024-05-01T18:30:00+02:00. Include the UTC offset when the page states a time zone, and give only the date, such as 2024-05-01, when no time is shown."
+          },
+          "endTime": {
+            "type": "string",
+            "format": "date-time",
+            "description": "When the event ends, in the same format as the start time."
+          },
+          "location": {
+            "type": "object",
+            "description": "Where the event takes place.",
+            "properties": {
+              "name": {
+                "type": "string",
+                "description": "The name of the venue, or Online for online events."
+              },
+              "address": {
+                "type": "string",
+                "description": "The street address of the venue."
+              }
+            }
+          },
+          "organizer": {
+            "type": "string",
+            "description": "The person or organization hosting the event."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL of the event's page."
+          }
+        }
+      }
+    },
+    "nextPageUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the next page of events, if any."
+    }
+  }
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EventList {
+    pub title: Option<String>,
+    #[serde(default)]
+    pub events: Vec<Event>,
+    #[serde(default)]
+    pub next_page_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Event {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub location: Option<Location>,
+    pub organizer: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Location {
+    pub name: Option<String>,
+    pub address: Option<String>,
+}
+
+/// A point in time as given by the page: a date, optionally with a time of
+/// day and a UTC offset.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EventTime {
+    pub date: NaiveDate,
+    pub time: Option<NaiveTime>,
+    pub offset: Option<FixedOffset>,
+}
+
+impl EventTime {
+    pub fn parse(text: &str) -> Option<EventTime> {
+        let text = text.trim();
+
+        if let Ok(date_time) = DateTime::parse_from_rfc3339(text) {
+            return Some(EventTime {
+                date: date_time.date_naive(),
+                time: Some(date_time.time()),
+                offset: Some(*date_time.offset()),
+            });
+        }
+
+        for format in [
+            "%Y-%m-%dT%H:%M:%S",
+            "%Y-%m-%dT%H:%M",
+            "%Y-%m-%d %H:%M:%S",
+            "%Y-%m-%d %H:%M",
+        ] {
+            if let Ok(date_time) = NaiveDateTime::parse_from_str(text, format) {
+                return Some(EventTime {
+                    date: date_time.date(),
+                    time: Some(date_time.time()),
+                    offset: None,
+                });
+            }
+        }
+
+        NaiveDate::parse_from_str(text, "%Y-%m-%d")
+            .ok()
+            .map(|date| EventTime {
+                date,
+                time: None,
+                offset: None,
+            })
+    }
+
+    fn sort_key(&self) -> NaiveDateTime {
+        self.date.and_time(self.time.unwrap_or(NaiveTime::MIN))
+    }
+
+    /// The end to export for an event starting at `self`. A date-only end is
+    /// exclusive in iCalendar, so it is moved to the following day; an end
+    /// that is not of the same kind as the start, or not after it, is left
+    /// out.
+    fn ics_end(self, end: EventTime) -> Option<EventTime> {
+        match (self.time, end.time) {
+            (None, None) if end.date >= self.date => Some(EventTime {
+                date: end.date.checked_add_days(Days::new(1))?,
+                ..end
+            }),
+            (Some(_), Some(_)) if end.instant() > self.instant() => Some(end),
+            _ => None,
+        }
+    }
+
+    /// The time in UTC where the offset is known, for comparing times.
+    fn instant(&self) -> NaiveDateTime {
+        match self.offset {
+            Some(offset) => self.sort_key() - offset,
+            None => self.sort_key(),
+        }
+    }
+
+    /// iCalendar property for this time, such as `DTSTART:20240501T163000Z`.
+    fn to_ics_property(self, name: &str) -> String {
+        match (self.time, self.offset) {
+            (None, _) => format!("{};VALUE=DATE:{}", name, self.date.format("%Y%m%d")),
+            (Some(time), Some(offset)) => {
+                let local = self.date.and_time(time);
+                let utc = local - offset;
+                format!("{}:{}", name, utc.format("%Y%m%dT%H%M%SZ"))
+            }
+            (Some(time), None) => {
+                format!(
+                    "{}:{}",
+                    name,
+                    self.date.and_time(time).format("%Y%m%dT%H%M%S")
+                )
+            }
+        }
+    }
+}
+
+impl Event {
+    pub fn start(&self) -> Option<EventTime> {
+        EventTime::parse(self.start_time.as_deref()?)
+    }
+
+    pub fn end(&self) -> Option<EventTime> {
+        EventTime::parse(self.end_time.as_deref()?)
+    }
+
+    /// Key used to recognise the same event across pages.
+    pub fn identity(&self) -> String {
+        match &self.url {
+            Some(url) => format!("url:{}", url),
+            None => format!(
+                "title:{}@{}",
+                self.title.as_deref().unwrap_or_default(),
+                self.start_time.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+
+    pub fn location_text(&self) -> Option<String> {
+        let location = self.location.as_ref()?;
+
+        match (&location.name, &location.address) {
+            (Some(name), Some(address)) => Some(format!("{}, {}", name, address)),
+            (Some(name), None) => Some(name.clone()),
+            (None, Some(address)) => Some(address.clone()),
+            (None, None) => None,
+        }
+    }
+}
+
+impl EventList {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        for event in &mut self.events {
+            event.url = event.url.as_deref().map(|url| resolve_url(base_url, url));
+        }
+
+        self.next_page_url = self
+            .next_page_url
+            .as_deref()
+            .map(|url| resolve_url(base_url, url));
+    }
+
+    /// Indices of the events grouped by the day they start on, in
+    /// chronological order. Events without a readable start time come last,
+    /// under `None`.
+    pub fn agenda(&self) -> Vec<(Option<NaiveDate>, Vec<usize>)> {
+        let mut indices: Vec<usize> = (0..self.events.len()).collect();
+        indices.sort_by_key(|index| {
+            let start = self.events[*index].start();
+            (start.is_none(), start.map(|start| start.sort_key()))
+        });
+
+        let mut agenda: Vec<(Option<NaiveDate>, Vec<usize>)> = Vec::new();
+
+        for index in indices {
+            let date = self.events[index].start().map(|start| start.date);
+
+            match agenda.last_mut() {
+                Some((last_date, events)) if *last_date == date => events.push(index),
+                _ => agenda.push((date, vec![index])),
+            }
+        }
+
+        agenda
+    }
+}
+
+impl Paginated for EventList {
+    type Item = Event;
+
+    fn items_mut(&mut self) -> &mut Vec<Event> {
+        &mut self.events
+    }
+
+    fn set_next_page_url(&mut self, next_page_url: Option<String>) {
+        self.next_page_url = next_page_url;
+    }
+
+    fn into_items(self) -> (Vec<Event>, Option<String>) {
+        (self.events, self.next_page_url)
+    }
+
+    fn item_key(item: &Event) -> Option<String> {
+        Some(item.identity())
+    }
+}
+
+/// Serializes events as an iCalendar file. Events without a readable start
+/// time are skipped.
+pub fn to_ics(events: &[&Event]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:-//{}//EN", PROGRAM_NAME),
+    ];
+
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ");
+
+    for event in events {
+        let Some(start) = event.start() else {
+            continue;
+        };
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!(
+            "UID:{:016x}@{}",
+            stable_hash(&event.identity()),
+            PROGRAM_NAME
+        ));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(start.to_ics_property("DTSTART"));
+
+        if let Some(end) = event.end().and_then(|end| start.ics_end(end)) {
+            lines.push(end.to_ics_property("DTEND"));
+        }
+
+        let properties = [
+            ("SUMMARY", event.title.clone()),
+            ("DESCRIPTION", event.description.clone()),
+            ("LOCATION", event.location_text()),
+        ];
+
+        for (name, value) in properties {
+            if let Some(value) = value {
+                lines.push(format!("{}:{}", name, escape_ics_text(&value)));
+            }
+        }
+
+        if let Some(url) = &event.url {
+            lines.push(format!("URL:{}", url));
+        }
+
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_ics_line(line))
+        .collect::<Vec<String>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+/// 64-bit FNV-1a, which unlike the standard library's hasher gives the same
+/// result in every build, so that re-exported events keep their UID.
+fn stable_hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn escape_ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+        .replace('\r', "")
+}
+
+/// Folds lines longer than 75 octets as required by RFC 5545.
+fn fold_ics_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+
+    for ch in line.chars() {
+        if length + ch.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+
+        folded.push(ch);
+        length += ch.len_utf8();
+    }
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(title: &str, start_time: &str, end_time: Option<&str>) -> Event {
+        Event {
+            title: Some(title.to_string()),
+            description: None,
+            start_time: Some(start_time.to_string()),
+            end_time: end_time.map(str::to_string),
+            location: None,
+            organizer: None,
+            url: None,
+        }
+    }
+
+    fn vevent_lines(ics: &str) -> Vec<&str> {
+        ics.split("\r\n")
+            .skip_while(|line| *line != "BEGIN:VEVENT")
+            .take_while(|line| *line != "END:VEVENT")
+            .filter(|line| !line.starts_with("DTSTAMP:"))
+            .collect()
+    }
+
+    #[test]
+    fn parses_dates_and_times() {
+        let date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+
+        assert_eq!(
+            EventTime::parse("2024-05-01"),
+            Some(EventTime {
+                date,
+                time: None,
+                offset: None
+            })
+        );
+        assert_eq!(
+            EventTime::parse(" 2024-05-01 16:30 "),
+            Some(EventTime {
+                date,
+                time: NaiveTime::from_hms_opt(16, 30, 0),
+                offset: None
+            })
+        );
+        assert_eq!(
+            EventTime::parse("2024-05-01T16:30:00+02:00"),
+            Some(EventTime {
+                date,
+                time: NaiveTime::from_hms_opt(16, 30, 0),
+                offset: FixedOffset::east_opt(2 * 3600)
+            })
+        );
+        assert_eq!(EventTime::parse("next Tuesday"), None);
+    }
+
+    #[test]
+    fn exports_timed_events_in_utc() {
+        let event = event(
+            "Launch",
+            "2024-05-01T16:30:00+02:00",
+            Some("2024-05-01T18:00:00+02:00"),
+        );
+        let ics = to_ics(&[&event]);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(
+            vevent_lines(&ics)[2..],
+            [
+                "DTSTART:20240501T143000Z",
+                "DTEND:20240501T160000Z",
+                "SUMMARY:Launch",
+            ]
+        );
+    }
+
+    #[test]
+    fn makes_date_only_ends_exclusive() {
+        let one_day = event("Fair", "2024-05-01", Some("2024-05-01"));
+        let ics = to_ics(&[&one_day]);
+
+        assert_eq!(
+            vevent_lines(&ics)[2..4],
+            ["DTSTART;VALUE=DATE:20240501", "DTEND;VALUE=DATE:20240502"]
+        );
+    }
+
+    #[test]
+    fn leaves_out_ends_that_do_not_match_the_start() {
+        let mixed = event("Fair", "2024-05-01", Some("2024-05-01T18:00"));
+        let backwards = event("Talk", "2024-05-01T18:00", Some("2024-05-01T17:00"));
+        let ics = to_ics(&[&mixed, &backwards]);
+
+        assert!(!ics.contains("DTEND"));
+    }
+
+    #[test]
+    fn keeps_uids_stable() {
+        let event = event("Launch", "2024-05-01", None);
+
+        assert_eq!(
+            vevent_lines(&to_ics(&[&event]))[1],
+            format!(
+                "UID:{:016x}@{}",
+                stable_hash(&event.identity()),
+                PROGRAM_NAME
+            )
+        );
+        assert_eq!(stable_hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(stable_hash("a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn escapes_text_values() {
+        assert_eq!(
+            escape_ics_text("Rock, paper; scissors\\\r\nNext"),
+            "Rock\\, paper\\; scissors\\\\\\nNext"
+        );
+    }
+
+    #[test]
+    fn folds_long_lines_without_splitting_characters() {
+        let line = format!("SUMMARY:{}", "é".repeat(40));
+        let folded = fold_ics_line(&line);
+
+        assert!(folded.split("\r\n").all(|part| part.len() <= 75));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+        assert_eq!(fold_ics_line("SUMMARY:Short"), "SUMMARY:Short");
+    }
+}
@@ -0,0 +1,305 @@
+use serde::{Deserialize, Serialize};
+
+use crate::content::digest::Author;
+use crate::content::paginated::Paginated;
+use crate::prelude::*;
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "forum_index",
+  "type": "object",
+  "description": "A forum index is the overview page of a discussion forum or one of its boards. Forums are organized as a hierarchy of categories containing boards, boards containing threads and threads containing posts. An index page lists the boards of each category with their number of threads and posts and the most recent post, or the threads of a single board with their number of replies and most recent post. Breadcrumbs usually show where the page sits in the hierarchy.",
+  "properties": {
+    "title": {
+      "type": "string",
+      "description": "The name of the forum or board."
+    },
+    "breadcrumbs": {
+      "type": "array",
+      "description": "The breadcrumb trail from the forum root to this page.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "label": {
+            "type": "string",
+            "description": "The text of the breadcrumb."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL of the page the breadcrumb points to."
+          }
+        }
+      }
+    },
+    "categories": {
+      "type": "array",
+      "description": "The categories of boards on the page.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "name": {
+            "type": "string",
+            "description": "The name of the category."
+          },
+          "boards": {
+            "type": "array",
+            "description": "The boards in the category.",
+            "items": {
+              "type": "object",
+              "properties": {
+                "name": {
+                  "type": "string",
+                  "description": "The name of the board."
+                },
+                "description": {
+                  "type": "string",
+                  "description": "The description of the board."
+                },
+                "url": {
+                  "type": "string",
+                  "format": "uri",
+                  "description": "The URL of the board."
+                },
+                "threadCount": {
+                  "type": "string",
+                  "description": "The number of threads or topics in the board, as displayed."
+                },
+                "postCount": {
+                  "type": "string",
+                  "description": "The number of posts in the board, as displayed."
+                },
+                "lastPost": {
+                  "type": "object",
+                  "description": "The most recent post.",
+                  "properties": {
+                    "title": {
+                      "type": "string",
+                      "description": "The title of the thread the post belongs to."
+                    },
+                    "author": {
+                      "type": "string",
+                      "description": "The name of the user who wrote the post."
+                    },
+                    "timestamp": {
+                      "type": "string",
+                      "format": "date-time",
+                      "description": "When the post was written."
+                    },
+                    "url": {
+                      "type": "string",
+                      "format": "uri",
+                      "description": "The URL of the post."
+                    }
+                  }
+                }
+              }
+            }
+          }
+        }
+      }
+    },
+    "threads": {
+      "type": "array",
+      "description": "The threads listed on the page, when the page is a single board.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "title": {
+            "type": "string",
+            "description": "The title of the thread."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL of the thread."
+          },
+          "author": {
+            "type": "object",
+            "description": "The user who started the thread.",
+            "properties": {
+              "name": {
+                "type": "string",
+                "description": "The name of the user."
+              },
+              "url": {
+                "type": "string",
+                "format": "uri",
+                "description": "The URL of the user's profile page."
+              }
+            }
+          },
+          "replyCount": {
+            "type": "string",
+            "description": "The number of replies, as displayed."
+          },
+          "viewCount": {
+            "type": "string",
+            "description": "The number of views, as displayed."
+          },
+          "lastPost": {
+            "type": "object",
+            "description": "The most recent post.",
+            "properties": {
+              "title": {
+                "type": "string",
+                "description": "The title of the thread the post belongs to."
+              },
+              "author": {
+                "type": "string",
+                "description": "The name of the user who wrote the post."
+              },
+              "timestamp": {
+                "type": "string",
+                "format": "date-time",
+                "description": "When the post was written."
+              },
+              "url": {
+                "type": "string",
+                "format": "uri",
+                "description": "The URL of the post."
+              }
+            }
+          }
+        }
+      }
+    },
+    "nextPageUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the next page of threads, if any."
+    }
+  }
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ForumIndex {
+    pub title: Option<String>,
+    #[serde(default)]
+    pub breadcrumbs: Vec<Breadcrumb>,
+    #[serde(default)]
+    pub categories: Vec<Category>,
+    #[serde(default)]
+    pub threads: Vec<ThreadSummary>,
+    #[serde(default)]
+    pub next_page_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Breadcrumb {
+    pub label: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Category {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub boards: Vec<Board>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Board {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub thread_count: Option<String>,
+    pub post_count: Option<String>,
+    pub last_post: Option<LastPost>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadSummary {
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub author: Option<Author>,
+    pub reply_count: Option<String>,
+    pub view_count: Option<String>,
+    pub last_post: Option<LastPost>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LastPost {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub timestamp: Option<String>,
+    pub url: Option<String>,
+}
+
+impl ForumIndex {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        let resolve = |url: &mut Option<String>| {
+            *url = url.as_deref().map(|url| resolve_url(base_url, url));
+        };
+
+        for breadcrumb in &mut self.breadcrumbs {
+            resolve(&mut breadcrumb.url);
+        }
+
+        for board in self
+            .categories
+            .iter_mut()
+            .flat_map(|category| category.boards.iter_mut())
+        {
+            resolve(&mut board.url);
+
+            if let Some(last_post) = &mut board.last_post {
+                resolve(&mut last_post.url);
+            }
+        }
+
+        for thread in &mut self.threads {
+            resolve(&mut thread.url);
+
+            if let Some(author) = &mut thread.author {
+                resolve(&mut author.url);
+            }
+
+            if let Some(last_post) = &mut thread.last_post {
+                resolve(&mut last_post.url);
+            }
+        }
+
+        resolve(&mut self.next_page_url);
+    }
+}
+
+impl Paginated for ForumIndex {
+    type Item = ThreadSummary;
+
+    fn items_mut(&mut self) -> &mut Vec<ThreadSummary> {
+        &mut self.threads
+    }
+
+    fn set_next_page_url(&mut self, next_page_url: Option<String>) {
+        self.next_page_url = next_page_url;
+    }
+
+    fn into_items(self) -> (Vec<ThreadSummary>, Option<String>) {
+        (self.threads, self.next_page_url)
+    }
+
+    fn item_key(item: &ThreadSummary) -> Option<String> {
+        item.url.clone()
+    }
+}
+
+/// The breadcrumb one level above the current page, which is assumed to be
+/// the last one in the trail.
+pub fn parent_breadcrumb(breadcrumbs: &[Breadcrumb]) -> Option<&Breadcrumb> {
+    let (_, ancestors) = breadcrumbs.split_last()?;
+
+    ancestors
+        .iter()
+        .rev()
+        .find(|breadcrumb| breadcrumb.url.is_some())
+}
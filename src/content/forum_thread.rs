@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+
+use crate::content::digest::Author;
+use crate::content::forum_index::Breadcrumb;
+use crate::prelude::*;
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "forum_thread",
+  "type": "object",
+  "description": "A forum thread is a page of a discussion forum showing the posts of a single topic in chronological order. Each post has an author and a timestamp and may quote earlier posts. Long threads are split across several pages. Breadcrumbs usually show the board and category the thread belongs to.",
+  "properties": {
+    "title": {
+      "type": "string",
+      "description": "The title of the thread."
+    },
+    "breadcrumbs": {
+      "type": "array",
+      "description": "The breadcrumb trail from the forum root to this thread.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "label": {
+            "type": "string",
+            "description": "The text of the breadcrumb."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL of the page the breadcrumb points to."
+          }
+        }
+      }
+    },
+    "posts": {
+      "type": "array",
+      "description": "The posts on this page of the thread, in order.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "author": {
+            "type": "object",
+            "description": "The user who wrote the post.",
+            "properties": {
+              "name": {
+                "type": "string",
+                "description": "The name of the user."
+              },
+              "url": {
+                "type": "string",
+                "format": "uri",
+                "description": "The URL of the user's profile page."
+              }
+            }
+          },
+          "timestamp": {
+            "type": "string",
+            "format": "date-time",
+            "description": "When the post was written."
+          },
+          "content": {
+            "type": "string",
+            "description": "The text of the post, excluding quoted text."
+          },
+          "quotes": {
+            "type": "array",
+            "description": "Earlier posts quoted in this post.",
+            "items": {
+              "type": "object",
+              "properties": {
+                "author": {
+                  "type": "string",
+                  "description": "The name of the user being quoted."
+                },
+                "text": {
+                  "type": "string",
+                  "description": "The quoted text."
+                }
+              }
+            }
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The permanent link to the post."
+          }
+        }
+      }
+    },
+    "currentPage": {
+      "type": "integer",
+      "description": "The number of this page of the thread."
+    },
+    "pageCount": {
+      "type": "integer",
+      "description": "The total number of pages in the thread."
+    },
+    "nextPageUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the next page of the thread, if any."
+    },
+    "previousPageUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the previous page of the thread, if any."
+    }
+  }
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ForumThread {
+    pub title: Option<String>,
+    #[serde(default)]
+    pub breadcrumbs: Vec<Breadcrumb>,
+    #[serde(default)]
+    pub posts: Vec<Post>,
+    pub current_page: Option<u64>,
+    pub page_count: Option<u64>,
+    #[serde(default)]
+    pub next_page_url: Option<String>,
+    #[serde(default)]
+    pub previous_page_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Post {
+    pub author: Option<Author>,
+    pub timestamp: Option<String>,
+    pub content: Option<String>,
+    #[serde(default)]
+    pub quotes: Vec<Quote>,
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Quote {
+    pub author: Option<String>,
+    pub text: Option<String>,
+}
+
+impl ForumThread {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        let resolve = |url: &mut Option<String>| {
+            *url = url.as_deref().map(|url| resolve_url(base_url, url));
+        };
+
+        for breadcrumb in &mut self.breadcrumbs {
+            resolve(&mut breadcrumb.url);
+        }
+
+        for post in &mut self.posts {
+            resolve(&mut post.url);
+
+            if let Some(author) = &mut post.author {
+                resolve(&mut author.url);
+            }
+        }
+
+        resolve(&mut self.next_page_url);
+        resolve(&mut self.previous_page_url);
+    }
+
+    /// Appends the posts of the following page of the thread.
+    pub fn append_page(&mut self, page: ForumThread) {
+        self.posts.extend(page.posts);
+        self.current_page = page.current_page.or(self.current_page);
+        self.next_page_url = page.next_page_url;
+    }
+}
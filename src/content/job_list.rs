@@ -0,0 +1,332 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::content::paginated::Paginated;
+use crate::prelude::*;
+use crate::storage;
+
+const SAVED_FILTERS_FILE_NAME: &str = "job_filters.json";
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "job_list",
+  "type": "object",
+  "description": "A job listing is a page of a job board or a company's careers site that lists open positions. Each job is shown with its title, the hiring company, where it is located and whether it can be done remotely, often a salary range and when it was posted, and links to the full posting.",
+  "properties": {
+    "title": {
+      "type": "string",
+      "description": "The title of the page, such as the name of the board or the search."
+    },
+    "jobs": {
+      "type": "array",
+      "description": "The jobs listed on the page.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "title": {
+            "type": "string",
+            "description": "The title of the position."
+          },
+          "company": {
+            "type": "string",
+            "description": "The name of the hiring company."
+          },
+          "location": {
+            "type": "string",
+            "description": "Where the job is located, such as a city and country."
+          },
+          "remote": {
+            "type": "boolean",
+            "description": "Whether the job can be done remotely."
+          },
+          "salary": {
+            "type": "object",
+            "description": "The salary range offered, if stated.",
+            "properties": {
+              "min": {
+                "type": "number",
+                "description": "The lower end of the range, written out in full, such as 120000 for 120k."
+              },
+              "max": {
+                "type": "number",
+                "description": "The upper end of the range, written out in full."
+              },
+              "currency": {
+                "type": "string",
+                "description": "The currency, as an ISO 4217 code such as USD or a symbol such as $."
+              },
+              "period": {
+                "type": "string",
+                "enum": ["hour", "day", "week", "month", "year"],
+                "description": "The period the amounts are paid for."
+              }
+            }
+          },
+          "postedDate": {
+            "type": "string",
+            "format": "date",
+            "description": "When the job was posted, in ISO 8601 format such as 2024-05-01."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL of the job posting."
+          },
+          "applyUrl": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL used to apply for the job, if different from the posting."
+          }
+        }
+      }
+    },
+    "nextPageUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the next page of jobs, if any."
+    }
+  }
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct JobList {
+    pub title: Option<String>,
+    #[serde(default)]
+    pub jobs: Vec<JobSummary>,
+    #[serde(default)]
+    pub next_page_url: Option<String>,
+    /// Host of the board the listing was loaded from, which saved filters
+    /// are kept for.
+    #[serde(skip)]
+    pub board: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSummary {
+    pub title: Option<String>,
+    pub company: Option<String>,
+    pub location: Option<String>,
+    pub remote: Option<bool>,
+    pub salary: Option<SalaryRange>,
+    pub posted_date: Option<String>,
+    pub url: Option<String>,
+    pub apply_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SalaryRange {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub currency: Option<String>,
+    pub period: Option<String>,
+}
+
+/// Filters applied to a job listing, saved per board so that they are
+/// applied again on later visits.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JobFilters {
+    /// Text the location has to contain, ignoring case.
+    pub location: Option<String>,
+    pub remote_only: bool,
+    /// Minimum yearly salary. Jobs that do not state a salary are kept.
+    pub min_salary: Option<f64>,
+}
+
+impl JobList {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        for job in &mut self.jobs {
+            job.url = job.url.as_deref().map(|url| resolve_url(base_url, url));
+            job.apply_url = job
+                .apply_url
+                .as_deref()
+                .map(|url| resolve_url(base_url, url));
+        }
+
+        self.next_page_url = self
+            .next_page_url
+            .as_deref()
+            .map(|url| resolve_url(base_url, url));
+
+        self.board = Some(minimize_url(base_url));
+    }
+}
+
+impl Paginated for JobList {
+    type Item = JobSummary;
+
+    fn items_mut(&mut self) -> &mut Vec<JobSummary> {
+        &mut self.jobs
+    }
+
+    fn set_next_page_url(&mut self, next_page_url: Option<String>) {
+        self.next_page_url = next_page_url;
+    }
+
+    fn into_items(self) -> (Vec<JobSummary>, Option<String>) {
+        (self.jobs, self.next_page_url)
+    }
+
+    fn item_key(item: &JobSummary) -> Option<String> {
+        item.url.clone()
+    }
+}
+
+impl JobSummary {
+    pub fn is_remote(&self) -> bool {
+        self.remote.unwrap_or(false)
+            || self
+                .location
+                .as_deref()
+                .is_some_and(|location| location.to_lowercase().contains("remote"))
+    }
+}
+
+impl SalaryRange {
+    /// Upper end of the range converted to a yearly amount, assuming full
+    /// time work for hourly, daily and weekly pay.
+    pub fn yearly_max(&self) -> Option<f64> {
+        let amount = self.max.or(self.min)?;
+
+        let factor = match self.period.as_deref().map(str::to_lowercase).as_deref() {
+            Some("hour") => 2080.0,
+            Some("day") => 260.0,
+            Some("week") => 52.0,
+            Some("month") => 12.0,
+            _ => 1.0,
+        };
+
+        Some(amount * factor)
+    }
+}
+
+impl JobFilters {
+    pub fn is_empty(&self) -> bool {
+        *self == JobFilters::default()
+    }
+
+    pub fn matches(&self, job: &JobSummary) -> bool {
+        if let Some(location) = &self.location {
+            let matches_location = job
+                .location
+                .as_deref()
+                .is_some_and(|text| text.to_lowercase().contains(&location.to_lowercase()));
+
+            if !matches_location {
+                return false;
+            }
+        }
+
+        if self.remote_only && !job.is_remote() {
+            return false;
+        }
+
+        if let Some(min_salary) = self.min_salary
+            && let Some(yearly_max) = job.salary.as_ref().and_then(SalaryRange::yearly_max)
+            && yearly_max < min_salary
+        {
+            return false;
+        }
+
+        true
+    }
+
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+
+        if let Some(location) = &self.location {
+            parts.push(format!("location: {}", location));
+        }
+
+        if self.remote_only {
+            parts.push("remote only".to_string());
+        }
+
+        if let Some(min_salary) = self.min_salary {
+            parts.push(format!("salary ≥ {}", format_amount(min_salary)));
+        }
+
+        if parts.is_empty() {
+            "no filters".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+
+    /// Filters saved for `board`, if any.
+    pub fn load_saved(board: &str) -> Option<JobFilters> {
+        let mut saved: HashMap<String, JobFilters> = storage::load(SAVED_FILTERS_FILE_NAME);
+        saved.remove(board)
+    }
+
+    /// Saves the filters for `board`, or forgets them when they are empty.
+    pub fn save(&self, board: &str) -> Result<(), Errors> {
+        let mut saved: HashMap<String, JobFilters> = storage::load(SAVED_FILTERS_FILE_NAME);
+
+        if self.is_empty() {
+            saved.remove(board);
+        } else {
+            saved.insert(board.to_string(), self.clone());
+        }
+
+        storage::save(SAVED_FILTERS_FILE_NAME, &saved)
+    }
+}
+
+/// Formats a salary range such as `$120,000–150,000 / year`.
+pub fn format_salary(salary: &SalaryRange) -> String {
+    let range = match (salary.min, salary.max) {
+        (Some(min), Some(max)) if min != max => {
+            format!("{}–{}", format_amount(min), format_amount(max))
+        }
+        (Some(amount), _) | (None, Some(amount)) => format_amount(amount),
+        (None, None) => return String::new(),
+    };
+
+    let range = match salary.currency.as_deref() {
+        Some(currency) if currency.chars().all(char::is_alphabetic) => {
+            format!("{} {}", range, currency)
+        }
+        Some(currency) => format!("{}{}", currency, range),
+        None => range,
+    };
+
+    match salary.period.as_deref() {
+        Some(period) => format!("{} / {}", range, period),
+        None => range,
+    }
+}
+
+/// Writes an amount with thousands separators, keeping cents only where
+/// there are any.
+fn format_amount(amount: f64) -> String {
+    let total_cents = (amount.abs() * 100.0).round() as u64;
+    let digits = (total_cents / 100).to_string();
+    let mut formatted = String::new();
+
+    for (index, ch) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            formatted.push(',');
+        }
+
+        formatted.push(ch);
+    }
+
+    if !total_cents.is_multiple_of(100) {
+        formatted.push_str(&format!(".{:02}", total_cents % 100));
+    }
+
+    if amount < 0.0 {
+        format!("-{}", formatted)
+    } else {
+        formatted
+    }
+}
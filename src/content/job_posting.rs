@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+use crate::content::job_list::SalaryRange;
+use crate::prelude::*;
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "job_posting",
+  "type": "object",
+  "description": "A job posting is a page describing a single open position, on a job board or a company's careers site. It names the position and the hiring company, where the job is located and whether it can be done remotely, the salary range if stated, when it was posted, a description of the role, and a link or button to apply.",
+  "properties": {
+    "title": {
+      "type": "string",
+      "description": "The title of the position."
+    },
+    "company": {
+      "type": "string",
+      "description": "The name of the hiring company."
+    },
+    "companyUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the company's page or website."
+    },
+    "location": {
+      "type": "string",
+      "description": "Where the job is located, such as a city and country."
+    },
+    "remote": {
+      "type": "boolean",
+      "description": "Whether the job can be done remotely."
+    },
+    "employmentType": {
+      "type": "string",
+      "description": "The kind of employment, such as full-time, part-time or contract."
+    },
+    "salary": {
+      "type": "object",
+      "description": "The salary range offered, if stated.",
+      "properties": {
+        "min": {
+          "type": "number",
+          "description": "The lower end of the range, written out in full, such as 120000 for 120k."
+        },
+        "max": {
+          "type": "number",
+          "description": "The upper end of the range, written out in full."
+        },
+        "currency": {
+          "type": "string",
+          "description": "The currency, as an ISO 4217 code such as USD or a symbol such as $."
+        },
+        "period": {
+          "type": "string",
+          "enum": ["hour", "day", "week", "month", "year"],
+          "description": "The period the amounts are paid for."
+        }
+      }
+    },
+    "postedDate": {
+      "type": "string",
+      "format": "date",
+      "description": "When the job was posted, in ISO 8601 format such as 2024-05-01."
+    },
+    "description": {
+      "type": "string",
+      "description": "The full description of the role, its responsibilities and requirements."
+    },
+    "applyUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL used to apply for the job."
+    }
+  }
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct JobPosting {
+    pub title: Option<String>,
+    pub company: Option<String>,
+    pub company_url: Option<String>,
+    pub location: Option<String>,
+    pub remote: Option<bool>,
+    pub employment_type: Option<String>,
+    pub salary: Option<SalaryRange>,
+    pub posted_date: Option<String>,
+    pub description: Option<String>,
+    pub apply_url: Option<String>,
+}
+
+impl JobPosting {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        let resolve = |url: &mut Option<String>| {
+            *url = url.as_deref().map(|url| resolve_url(base_url, url));
+        };
+
+        resolve(&mut self.company_url);
+        resolve(&mut self.apply_url);
+    }
+}
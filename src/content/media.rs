@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "media",
+  "type": "object",
+  "description": "A media page presents a single video or audio recording, such as a page of a video platform, a podcast episode or a recorded talk. It shows the title, the channel or author that published it, how long it is, how often it was viewed and when it was published, a description, and usually a list of related recordings.",
+  "properties": {
+    "title": {
+      "type": "string",
+      "description": "The title of the video or recording."
+    },
+    "channel": {
+      "type": "string",
+      "description": "The name of the channel, show or author that published it."
+    },
+    "channelUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the channel's page."
+    },
+    "duration": {
+      "type": "string",
+      "description": "How long the recording is, such as 12:34 or 1:02:03."
+    },
+    "views": {
+      "type": "integer",
+      "description": "The number of views or plays, written out in full, such as 1200000 for 1.2M views."
+    },
+    "publishDate": {
+      "type": "string",
+      "format": "date",
+      "description": "When it was published, in ISO 8601 format such as 2024-05-01."
+    },
+    "description": {
+      "type": "string",
+      "description": "The description of the recording."
+    },
+    "chapters": {
+      "type": "array",
+      "description": "The chapters of the recording, in order.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "title": {
+            "type": "string",
+            "description": "The title of the chapter."
+          },
+          "startTime": {
+            "type": "string",
+            "description": "Where the chapter starts, such as 0:00 or 1:23:45."
+          }
+        }
+      }
+    },
+    "mediaUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the recording itself, as it would be passed to a media player. Use the URL of the page when there is no separate media URL."
+    },
+    "related": {
+      "type": "array",
+      "description": "Related or recommended recordings shown alongside.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "title": {
+            "type": "string",
+            "description": "The title of the related recording."
+          },
+          "channel": {
+            "type": "string",
+            "description": "The channel or author that published it."
+          },
+          "duration": {
+            "type": "string",
+            "description": "How long it is, such as 12:34."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL of its page."
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Media {
+    pub title: Option<String>,
+    pub channel: Option<String>,
+    pub channel_url: Option<String>,
+    pub duration: Option<String>,
+    pub views: Option<u64>,
+    pub publish_date: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub chapters: Vec<Chapter>,
+    pub media_url: Option<String>,
+    #[serde(default)]
+    pub related: Vec<RelatedMedia>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Chapter {
+    pub title: Option<String>,
+    pub start_time: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RelatedMedia {
+    pub title: Option<String>,
+    pub channel: Option<String>,
+    pub duration: Option<String>,
+    pub url: Option<String>,
+}
+
+impl Media {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        let resolve = |url: &mut Option<String>| {
+            *url = url.as_deref().map(|url| resolve_url(base_url, url));
+        };
+
+        resolve(&mut self.channel_url);
+        resolve(&mut self.media_url);
+
+        for related in &mut self.related {
+            resolve(&mut related.url);
+        }
+    }
+}
+
+/// Formats durations given in ISO 8601 form, such as `PT1H2M3S`, as
+/// `1:02:03`. Other text is returned unchanged.
+pub fn format_duration(duration: &str) -> String {
+    let Some(components) = duration.trim().strip_prefix("PT") else {
+        return duration.trim().to_string();
+    };
+
+    let mut seconds = 0;
+    let mut number = String::new();
+    let mut fraction = false;
+
+    for ch in components.chars() {
+        match ch {
+            '0'..='9' if fraction => {}
+            '0'..='9' => number.push(ch),
+            '.' | ',' => fraction = true,
+            'H' | 'M' | 'S' => {
+                let Ok(value) = number.parse::<u64>() else {
+                    return duration.to_string();
+                };
+
+                seconds += value
+                    * match ch {
+                        'H' => 3600,
+                        'M' => 60,
+                        _ => 1,
+                    };
+                number.clear();
+                fraction = false;
+            }
+            _ => return duration.to_string(),
+        }
+    }
+
+    match (seconds / 3600, seconds / 60 % 60, seconds % 60) {
+        (0, minutes, seconds) => format!("{}:{:02}", minutes, seconds),
+        (hours, minutes, seconds) => format!("{}:{:02}:{:02}", hours, minutes, seconds),
+    }
+}
+
+/// Writes a view count with thousands separators, such as `1,234,567`.
+pub fn format_views(views: u64) -> String {
+    let digits = views.to_string();
+    let mut formatted = String::new();
+
+    for (index, ch) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            formatted.push(',');
+        }
+
+        formatted.push(ch);
+    }
+
+    formatted
+}
@@ -1,61 +1,516 @@
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::config::Config;
 use crate::prelude::*;
+use crate::storage;
 
+pub mod custom;
 pub mod digest;
+pub mod documentation;
+pub mod event_list;
+pub mod forum_index;
+pub mod forum_thread;
+pub mod job_list;
+pub mod job_posting;
+pub mod media;
+pub mod paginated;
+pub mod product;
+pub mod product_list;
+pub mod profile;
+pub mod repository;
+pub mod search_results;
+pub mod table;
+pub mod validation;
 
-use crate::content::digest::{Digest, deserialize_to_digest};
+use crate::content::custom::{CustomContent, CustomContentType};
+use crate::content::digest::Digest;
+use crate::content::documentation::Documentation;
+use crate::content::event_list::EventList;
+use crate::content::forum_index::ForumIndex;
+use crate::content::forum_thread::ForumThread;
+use crate::content::job_list::JobList;
+use crate::content::job_posting::JobPosting;
+use crate::content::media::Media;
+use crate::content::product::Product;
+use crate::content::product_list::ProductList;
+use crate::content::profile::Profile;
+use crate::content::repository::Repository;
+use crate::content::search_results::SearchResults;
+use crate::content::table::Table;
+use crate::content::validation::FieldProblem;
 
-#[derive(Debug, Clone)]
+const CONTENT_TYPE_OVERRIDES_FILE_NAME: &str = "content_type_overrides.json";
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum ContentType {
     Digest,
+    SearchResults,
+    ProductList,
+    Product,
+    Profile,
+    ForumIndex,
+    ForumThread,
+    EventList,
+    Documentation,
+    Table,
+    Media,
+    JobList,
+    JobPosting,
+    Repository,
+    Custom(Arc<CustomContentType>),
+}
+
+impl ContentType {
+    pub const BUILT_IN: &[ContentType] = &[
+        ContentType::Digest,
+        ContentType::SearchResults,
+        ContentType::ProductList,
+        ContentType::Product,
+        ContentType::Profile,
+        ContentType::ForumIndex,
+        ContentType::ForumThread,
+        ContentType::EventList,
+        ContentType::Documentation,
+        ContentType::Table,
+        ContentType::Media,
+        ContentType::JobList,
+        ContentType::JobPosting,
+        ContentType::Repository,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            ContentType::Digest => "digest",
+            ContentType::SearchResults => "search_results",
+            ContentType::ProductList => "product_list",
+            ContentType::Product => "product",
+            ContentType::Profile => "profile",
+            ContentType::ForumIndex => "forum_index",
+            ContentType::ForumThread => "forum_thread",
+            ContentType::EventList => "event_list",
+            ContentType::Documentation => "documentation",
+            ContentType::Table => "table",
+            ContentType::Media => "media",
+            ContentType::JobList => "job_list",
+            ContentType::JobPosting => "job_posting",
+            ContentType::Repository => "repository",
+            ContentType::Custom(custom) => &custom.name,
+        }
+    }
 }
 
 const DIGEST_NAMES: &[&str] = &["digest", "feed", "aggregator", "list"];
+const SEARCH_RESULTS_NAMES: &[&str] = &[
+    "search results",
+    "search_results",
+    "search results page",
+    "search engine results page",
+    "serp",
+];
+const PRODUCT_LIST_NAMES: &[&str] = &[
+    "product list",
+    "product_list",
+    "product listing",
+    "catalog",
+    "catalogue",
+    "e-commerce",
+    "ecommerce",
+];
+const PRODUCT_NAMES: &[&str] = &[
+    "product",
+    "product page",
+    "product detail",
+    "product details",
+];
+const PROFILE_NAMES: &[&str] = &[
+    "profile",
+    "user profile",
+    "profile page",
+    "user page",
+    "author page",
+    "member profile",
+];
+const FORUM_INDEX_NAMES: &[&str] = &[
+    "forum index",
+    "forum_index",
+    "message board",
+    "bulletin board",
+    "board index",
+];
+const FORUM_THREAD_NAMES: &[&str] = &[
+    "forum thread",
+    "forum_thread",
+    "discussion thread",
+    "forum topic",
+];
+const EVENT_LIST_NAMES: &[&str] = &[
+    "event list",
+    "event_list",
+    "events",
+    "event listing",
+    "calendar",
+    "agenda",
+    "meetup",
+];
+const DOCUMENTATION_NAMES: &[&str] = &[
+    "documentation",
+    "docs",
+    "manual",
+    "api documentation",
+    "api reference",
+];
+const TABLE_NAMES: &[&str] = &[
+    "table",
+    "data table",
+    "tabular data",
+    "ranking",
+    "rankings",
+    "leaderboard",
+    "league table",
+    "status page",
+    "price list",
+    "price sheet",
+    "comparison table",
+];
+const MEDIA_NAMES: &[&str] = &[
+    "media",
+    "video",
+    "video page",
+    "watch page",
+    "podcast",
+    "podcast episode",
+];
+const JOB_LIST_NAMES: &[&str] = &[
+    "job list",
+    "job_list",
+    "job listing",
+    "job listings",
+    "job board",
+    "jobs",
+    "careers",
+    "careers page",
+    "job search results",
+];
+const JOB_POSTING_NAMES: &[&str] = &[
+    "job posting",
+    "job_posting",
+    "job post",
+    "job ad",
+    "job advertisement",
+    "job description",
+    "vacancy",
+    "job offer",
+];
+const REPOSITORY_NAMES: &[&str] = &[
+    "repository",
+    "code repository",
+    "source code repository",
+    "git repository",
+    "repo",
+    "repository page",
+    "file listing",
+    "issue tracker",
+    "commit history",
+];
 
 #[derive(Debug, Clone)]
 pub enum ContentPayload {
     Digest(digest::Digest),
+    SearchResults(SearchResults),
+    ProductList(ProductList),
+    Product(Product),
+    Profile(Profile),
+    ForumIndex(ForumIndex),
+    ForumThread(ForumThread),
+    EventList(EventList),
+    Documentation(Documentation),
+    Table(Table),
+    Media(Media),
+    JobList(JobList),
+    JobPosting(JobPosting),
+    Repository(Repository),
+    Custom(CustomContent),
+}
+
+/// Content deserialized from a translation, with the problems of the fields
+/// that were dropped for not matching the schema.
+#[derive(Debug, Clone)]
+pub struct Translated {
+    pub payload: ContentPayload,
+    pub problems: Vec<FieldProblem>,
 }
 
 pub struct Content {}
 
 impl Content {
-    pub fn match_content_names(content_names: Vec<String>) -> Option<ContentType> {
-        let known_names = [(DIGEST_NAMES, ContentType::Digest)];
+    /// Built-in content types followed by those defined by the user.
+    pub fn all_content_types(config: &Config) -> Vec<ContentType> {
+        ContentType::BUILT_IN
+            .iter()
+            .cloned()
+            .chain(
+                config
+                    .custom_content_types
+                    .iter()
+                    .map(|custom| ContentType::Custom(Arc::clone(custom))),
+            )
+            .collect()
+    }
+
+    pub fn content_type_from_name(config: &Config, name: &str) -> Option<ContentType> {
+        Content::all_content_types(config)
+            .into_iter()
+            .find(|content_type| content_type.as_str().eq_ignore_ascii_case(name))
+    }
 
-        for (names, content_type) in known_names {
-            let has_match = content_names
+    /// Picks a content type for the names parversion detected, which come
+    /// ranked by confidence, so the first name that any type claims decides.
+    /// User-defined types are checked first for each name so that they can
+    /// refine the built-in ones.
+    pub fn match_content_names(config: &Config, content_names: Vec<String>) -> Option<ContentType> {
+        let known_names = [
+            (DIGEST_NAMES, ContentType::Digest),
+            (SEARCH_RESULTS_NAMES, ContentType::SearchResults),
+            (PRODUCT_LIST_NAMES, ContentType::ProductList),
+            (PRODUCT_NAMES, ContentType::Product),
+            (PROFILE_NAMES, ContentType::Profile),
+            (FORUM_THREAD_NAMES, ContentType::ForumThread),
+            (FORUM_INDEX_NAMES, ContentType::ForumIndex),
+            (EVENT_LIST_NAMES, ContentType::EventList),
+            (DOCUMENTATION_NAMES, ContentType::Documentation),
+            (TABLE_NAMES, ContentType::Table),
+            (MEDIA_NAMES, ContentType::Media),
+            (JOB_POSTING_NAMES, ContentType::JobPosting),
+            (JOB_LIST_NAMES, ContentType::JobList),
+            (REPOSITORY_NAMES, ContentType::Repository),
+        ];
+
+        for name in &content_names {
+            if let Some(custom) = config
+                .custom_content_types
                 .iter()
-                .any(|item| names.contains(&item.to_lowercase().as_str()));
+                .find(|custom| custom.matches(name))
+            {
+                return Some(ContentType::Custom(Arc::clone(custom)));
+            }
 
-            if has_match {
-                return Some(content_type);
+            let name = name.to_lowercase();
+
+            if let Some((_, content_type)) = known_names
+                .iter()
+                .find(|(names, _)| names.contains(&name.as_str()))
+            {
+                return Some(content_type.clone());
             }
         }
 
         None
     }
 
-    pub fn get_json_schema_by_content_type(content_type: &ContentType) -> &str {
+    /// Content type forced for a URL, either in the config or remembered
+    /// from an earlier choice for the same page. Exact URLs in the config
+    /// take precedence over domains.
+    pub fn get_content_type_override(config: &Config, url: &str) -> Option<ContentType> {
+        let remembered: HashMap<String, String> = storage::load(CONTENT_TYPE_OVERRIDES_FILE_NAME);
+
+        let name = config
+            .content_types
+            .get(url)
+            .or_else(|| remembered.get(url))
+            .or_else(|| find_domain_entry(&config.content_types, url))?;
+
+        let content_type = Content::content_type_from_name(config, name);
+
+        if content_type.is_none() {
+            log::warn!("Unknown content type override: {}", name);
+        }
+
+        content_type
+    }
+
+    /// Remembers the content type chosen for `url`.
+    pub fn remember_content_type_override(url: &str, content_type: &ContentType) {
+        let mut remembered: HashMap<String, String> =
+            storage::load(CONTENT_TYPE_OVERRIDES_FILE_NAME);
+
+        remembered.insert(url.to_string(), content_type.as_str().to_string());
+
+        if let Err(e) = storage::save(CONTENT_TYPE_OVERRIDES_FILE_NAME, &remembered) {
+            log::warn!("Could not save content type override: {:?}", e);
+        }
+    }
+
+    /// Forgets the content type chosen for `url`, returning whether one was
+    /// remembered.
+    pub fn forget_content_type_override(url: &str) -> bool {
+        let mut remembered: HashMap<String, String> =
+            storage::load(CONTENT_TYPE_OVERRIDES_FILE_NAME);
+
+        if remembered.remove(url).is_none() {
+            return false;
+        }
+
+        if let Err(e) = storage::save(CONTENT_TYPE_OVERRIDES_FILE_NAME, &remembered) {
+            log::warn!("Could not save content type overrides: {:?}", e);
+        }
+
+        true
+    }
+
+    pub fn get_json_schema_by_content_type(content_type: &ContentType) -> String {
         match content_type {
-            ContentType::Digest => Digest::get_json_schema(),
+            ContentType::Digest => Digest::get_json_schema().to_string(),
+            ContentType::SearchResults => SearchResults::get_json_schema().to_string(),
+            ContentType::ProductList => ProductList::get_json_schema().to_string(),
+            ContentType::Product => Product::get_json_schema().to_string(),
+            ContentType::Profile => Profile::get_json_schema().to_string(),
+            ContentType::ForumIndex => ForumIndex::get_json_schema().to_string(),
+            ContentType::ForumThread => ForumThread::get_json_schema().to_string(),
+            ContentType::EventList => EventList::get_json_schema().to_string(),
+            ContentType::Documentation => Documentation::get_json_schema().to_string(),
+            ContentType::Table => Table::get_json_schema().to_string(),
+            ContentType::Media => Media::get_json_schema().to_string(),
+            ContentType::JobList => JobList::get_json_schema().to_string(),
+            ContentType::JobPosting => JobPosting::get_json_schema().to_string(),
+            ContentType::Repository => Repository::get_json_schema().to_string(),
+            ContentType::Custom(custom) => custom.schema.to_string(),
         }
     }
 
+    /// Deserializes translated data after validating it against the schema
+    /// of `content_type`. Mismatches that can be coerced are fixed in place;
+    /// data that is structurally invalid yields `Errors::ValidationError`,
+    /// while fields that were dropped are returned with the content.
     pub fn content_data_to_payload(
         content_type: &ContentType,
         data: &str,
-    ) -> Result<ContentPayload, Errors> {
-        match content_type {
-            ContentType::Digest => {
-                let digest: Digest = deserialize_to_digest(data).map_err(|e| {
-                    Errors::TranslationError(format!(
-                        "Could not deserialize translated content: {}",
-                        e
-                    ))
-                })?;
-
-                Ok(ContentPayload::Digest(digest))
-            }
+    ) -> Result<Translated, Errors> {
+        let value: Value = serde_json::from_str(data).map_err(|e| {
+            Errors::ValidationError(vec![format!("Translated content is not valid JSON: {}", e)])
+        })?;
+
+        let mut value = unwrap_content(value, content_type.as_str());
+
+        let schema: Value = serde_json::from_str(&Content::get_json_schema_by_content_type(
+            content_type,
+        ))
+        .map_err(|e| Errors::UnexpectedError(format!("Could not parse JSON schema: {}", e)))?;
+
+        let report = validation::validate(&schema, &mut value);
+
+        for coercion in &report.coercions {
+            log::debug!("Coerced translated content: {}", coercion);
+        }
+
+        for problem in &report.problems {
+            log::warn!("Invalid translated content: {}", problem);
+        }
+
+        if report.is_structurally_invalid() {
+            return Err(Errors::ValidationError(
+                report.problems.iter().map(ToString::to_string).collect(),
+            ));
+        }
+
+        let payload = match content_type {
+            ContentType::Digest => ContentPayload::Digest(from_translated(value)?),
+            ContentType::SearchResults => ContentPayload::SearchResults(from_translated(value)?),
+            ContentType::ProductList => ContentPayload::ProductList(from_translated(value)?),
+            ContentType::Product => ContentPayload::Product(from_translated(value)?),
+            ContentType::Profile => ContentPayload::Profile(from_translated(value)?),
+            ContentType::ForumIndex => ContentPayload::ForumIndex(from_translated(value)?),
+            ContentType::ForumThread => ContentPayload::ForumThread(from_translated(value)?),
+            ContentType::EventList => ContentPayload::EventList(from_translated(value)?),
+            ContentType::Documentation => ContentPayload::Documentation(from_translated(value)?),
+            ContentType::Table => ContentPayload::Table(from_translated(value)?),
+            ContentType::Media => ContentPayload::Media(from_translated(value)?),
+            ContentType::JobList => ContentPayload::JobList(from_translated(value)?),
+            ContentType::JobPosting => ContentPayload::JobPosting(from_translated(value)?),
+            ContentType::Repository => ContentPayload::Repository(from_translated(value)?),
+            ContentType::Custom(custom) => ContentPayload::Custom(CustomContent {
+                content_type: Arc::clone(custom),
+                data: value,
+            }),
+        };
+
+        Ok(Translated {
+            payload,
+            problems: report.problems,
+        })
+    }
+}
+
+fn from_translated<T: DeserializeOwned>(value: Value) -> Result<T, Errors> {
+    serde_json::from_value(value).map_err(|e| {
+        Errors::ValidationError(vec![format!(
+            "Could not deserialize translated content: {}",
+            e
+        )])
+    })
+}
+
+/// Translations sometimes wrap the data in an object keyed by the content
+/// type name; returns the inner value in that case.
+fn unwrap_content(value: Value, name: &str) -> Value {
+    match value {
+        Value::Object(mut obj) if obj.len() == 1 && obj.contains_key(name) => {
+            obj.remove(name).unwrap_or_default()
         }
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn matches_names_in_ranked_order() {
+        let config = Config::default();
+
+        assert_eq!(
+            Content::match_content_names(&config, names(&["aggregator", "forum index"])),
+            Some(ContentType::Digest)
+        );
+        assert_eq!(
+            Content::match_content_names(&config, names(&["Forum Thread", "feed"])),
+            Some(ContentType::ForumThread)
+        );
+    }
+
+    #[test]
+    fn skips_names_no_type_claims() {
+        let config = Config::default();
+
+        assert_eq!(
+            Content::match_content_names(&config, names(&["forum", "news", "feed"])),
+            Some(ContentType::Digest)
+        );
+        assert_eq!(
+            Content::match_content_names(&config, names(&["thread"])),
+            None
+        );
+    }
+
+    #[test]
+    fn returns_dropped_fields_with_the_content() {
+        let data = r#"{ "entries": [{ "title": { "text": "Hello" }, "url": "https://a.com" }] }"#;
+
+        let translated = Content::content_data_to_payload(&ContentType::Digest, data).unwrap();
+
+        let ContentPayload::Digest(digest) = translated.payload else {
+            panic!("expected a digest");
+        };
+        assert_eq!(digest.entries.len(), 1);
+        assert_eq!(digest.entries[0].title, None);
+        assert_eq!(translated.problems.len(), 1);
+        assert!(translated.problems[0].path.ends_with("title"));
     }
 }
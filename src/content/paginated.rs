@@ -0,0 +1,32 @@
+use std::collections::HashSet;
+
+/// Content listed over several pages, each linking to the following one.
+pub trait Paginated: Sized {
+    type Item;
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item>;
+
+    fn set_next_page_url(&mut self, next_page_url: Option<String>);
+
+    /// Splits a page into its items and the link to the page after it.
+    fn into_items(self) -> (Vec<Self::Item>, Option<String>);
+
+    /// Key recognising the same item on two pages. Items without one are
+    /// never taken for duplicates.
+    fn item_key(item: &Self::Item) -> Option<String>;
+
+    /// Appends the items of a following page, skipping any already present.
+    fn append_page(&mut self, page: Self) {
+        let (page_items, next_page_url) = page.into_items();
+        let items = self.items_mut();
+        let mut keys: HashSet<String> = items.iter().filter_map(Self::item_key).collect();
+
+        for item in page_items {
+            if Self::item_key(&item).is_none_or(|key| keys.insert(key)) {
+                items.push(item);
+            }
+        }
+
+        self.set_next_page_url(next_page_url);
+    }
+}
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+
+use crate::content::product_list::deserialize_amount;
+use crate::prelude::*;
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "product",
+  "type": "object",
+  "description": "A product page describes a single product for sale in detail. Besides the name and price it typically includes a description, a table of specifications, the variants the product is offered in, such as sizes or colours, and a summary of customer reviews.",
+  "properties": {
+    "name": {
+      "type": "string",
+      "description": "The name of the product."
+    },
+    "description": {
+      "type": "string",
+      "description": "The description of the product."
+    },
+    "price": {
+      "type": ["number", "string"],
+      "description": "The current price of the product, without currency symbols."
+    },
+    "currency": {
+      "type": "string",
+      "description": "The currency of the price, as an ISO 4217 code or symbol."
+    },
+    "availability": {
+      "type": "string",
+      "description": "Whether the product is in stock, as displayed."
+    },
+    "specs": {
+      "type": "array",
+      "description": "The specifications of the product.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "name": {
+            "type": "string",
+            "description": "The name of the specification, such as weight."
+          },
+          "value": {
+            "type": "string",
+            "description": "The value of the specification, including units."
+          }
+        }
+      }
+    },
+    "variants": {
+      "type": "array",
+      "description": "The variants the product is offered in.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "name": {
+            "type": "string",
+            "description": "The name of the variant, such as a size or colour."
+          },
+          "price": {
+            "type": ["number", "string"],
+            "description": "The price of the variant, if it differs."
+          },
+          "availability": {
+            "type": "string",
+            "description": "Whether the variant is in stock, as displayed."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL of the variant, if it has its own page."
+          }
+        }
+      }
+    },
+    "reviewsSummary": {
+      "type": "object",
+      "description": "A summary of customer reviews of the product.",
+      "properties": {
+        "rating": {
+          "type": ["number", "string"],
+          "description": "The average rating."
+        },
+        "ratingScale": {
+          "type": ["number", "string"],
+          "description": "The highest possible rating, such as 5 or 10."
+        },
+        "reviewCount": {
+          "type": "integer",
+          "description": "The number of reviews."
+        },
+        "summary": {
+          "type": "string",
+          "description": "A summary of what reviewers say."
+        },
+        "url": {
+          "type": "string",
+          "format": "uri",
+          "description": "The URL of the full list of reviews."
+        }
+      }
+    }
+  }
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Product {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub price: Option<f64>,
+    pub currency: Option<String>,
+    pub availability: Option<String>,
+    #[serde(default)]
+    pub specs: Vec<Spec>,
+    #[serde(default)]
+    pub variants: Vec<Variant>,
+    pub reviews_summary: Option<ReviewsSummary>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Spec {
+    pub name: Option<String>,
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Variant {
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub price: Option<f64>,
+    pub availability: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewsSummary {
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub rating: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub rating_scale: Option<f64>,
+    pub review_count: Option<u64>,
+    pub summary: Option<String>,
+    pub url: Option<String>,
+}
+
+impl Product {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        for variant in &mut self.variants {
+            variant.url = variant.url.as_deref().map(|url| resolve_url(base_url, url));
+        }
+
+        if let Some(reviews_summary) = &mut self.reviews_summary {
+            reviews_summary.url = reviews_summary
+                .url
+                .as_deref()
+                .map(|url| resolve_url(base_url, url));
+        }
+    }
+}
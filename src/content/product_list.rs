@@ -0,0 +1,348 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::cmp::Ordering;
+
+use crate::content::paginated::Paginated;
+use crate::prelude::*;
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "product_list",
+  "type": "object",
+  "description": "A product listing is a page of a shop or catalog that presents several products for sale, such as a category page, search results within a store or a procurement catalog. Each product is usually shown with its name, price, a rating and whether it is in stock, and links to a page with more details.",
+  "properties": {
+    "title": {
+      "type": "string",
+      "description": "The title of the listing, such as the category or store name."
+    },
+    "products": {
+      "type": "array",
+      "description": "The products listed on the page.",
+      "items": {
+        "type": "object",
+        "description": "A product object",
+        "properties": {
+          "name": {
+            "type": "string",
+            "description": "The name of the product."
+          },
+          "price": {
+            "type": ["number", "string"],
+            "description": "The current price of the product, without currency symbols."
+          },
+          "currency": {
+            "type": "string",
+            "description": "The currency of the price, as an ISO 4217 code or symbol."
+          },
+          "rating": {
+            "type": ["number", "string"],
+            "description": "The average rating of the product."
+          },
+          "ratingScale": {
+            "type": ["number", "string"],
+            "description": "The highest possible rating, such as 5 or 10."
+          },
+          "availability": {
+            "type": "string",
+            "description": "Whether the product is in stock, as displayed."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL of the product detail page."
+          }
+        }
+      }
+    },
+    "nextPageUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the next page of products, if any."
+    }
+  }
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductList {
+    pub title: Option<String>,
+    #[serde(default)]
+    pub products: Vec<ProductSummary>,
+    #[serde(default)]
+    pub next_page_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductSummary {
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub price: Option<f64>,
+    pub currency: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub rating: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub rating_scale: Option<f64>,
+    pub availability: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProductOrder {
+    Listed,
+    PriceAscending,
+    PriceDescending,
+    Rating,
+}
+
+impl ProductOrder {
+    pub fn next(self) -> Self {
+        match self {
+            ProductOrder::Listed => ProductOrder::PriceAscending,
+            ProductOrder::PriceAscending => ProductOrder::PriceDescending,
+            ProductOrder::PriceDescending => ProductOrder::Rating,
+            ProductOrder::Rating => ProductOrder::Listed,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            ProductOrder::Listed => "as listed",
+            ProductOrder::PriceAscending => "price, lowest first",
+            ProductOrder::PriceDescending => "price, highest first",
+            ProductOrder::Rating => "rating, highest first",
+        }
+    }
+}
+
+impl ProductList {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        for product in &mut self.products {
+            product.url = product.url.as_deref().map(|url| resolve_url(base_url, url));
+        }
+
+        self.next_page_url = self
+            .next_page_url
+            .as_deref()
+            .map(|url| resolve_url(base_url, url));
+    }
+
+    /// Indices of the products in the given order. Products without the
+    /// value being sorted on are kept at the end in their listed order.
+    pub fn sorted_indices(&self, order: ProductOrder) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.products.len()).collect();
+
+        let compare = |a: Option<f64>, b: Option<f64>, descending: bool| match (a, b) {
+            (Some(a), Some(b)) if descending => b.partial_cmp(&a).unwrap_or(Ordering::Equal),
+            (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+
+        match order {
+            ProductOrder::Listed => {}
+            ProductOrder::PriceAscending => indices
+                .sort_by(|a, b| compare(self.products[*a].price, self.products[*b].price, false)),
+            ProductOrder::PriceDescending => indices
+                .sort_by(|a, b| compare(self.products[*a].price, self.products[*b].price, true)),
+            ProductOrder::Rating => indices.sort_by(|a, b| {
+                compare(
+                    self.products[*a].normalized_rating(),
+                    self.products[*b].normalized_rating(),
+                    true,
+                )
+            }),
+        }
+
+        indices
+    }
+}
+
+impl Paginated for ProductList {
+    type Item = ProductSummary;
+
+    fn items_mut(&mut self) -> &mut Vec<ProductSummary> {
+        &mut self.products
+    }
+
+    fn set_next_page_url(&mut self, next_page_url: Option<String>) {
+        self.next_page_url = next_page_url;
+    }
+
+    fn into_items(self) -> (Vec<ProductSummary>, Option<String>) {
+        (self.products, self.next_page_url)
+    }
+
+    fn item_key(item: &ProductSummary) -> Option<String> {
+        item.url.clone()
+    }
+}
+
+impl ProductSummary {
+    /// Rating on a scale of 0 to 5, so that products rated on different
+    /// scales compare sensibly.
+    pub fn normalized_rating(&self) -> Option<f64> {
+        let rating = self.rating?;
+
+        match self.rating_scale {
+            Some(scale) if scale > 0.0 => Some(rating / scale * 5.0),
+            _ => Some(rating),
+        }
+    }
+}
+
+pub fn format_price(price: Option<f64>, currency: Option<&str>) -> String {
+    match (price, currency) {
+        (Some(price), Some(currency)) if currency.chars().all(char::is_alphabetic) => {
+            format!("{:.2} {}", price, currency)
+        }
+        (Some(price), Some(currency)) => format!("{}{:.2}", currency, price),
+        (Some(price), None) => format!("{:.2}", price),
+        (None, _) => String::new(),
+    }
+}
+
+pub fn format_rating(rating: Option<f64>, scale: Option<f64>) -> String {
+    match (rating, scale) {
+        (Some(rating), Some(scale)) => format!("★ {:.1}/{}", rating, scale),
+        (Some(rating), None) => format!("★ {:.1}", rating),
+        (None, _) => String::new(),
+    }
+}
+
+/// Reads a price or rating given either as a number or as the text shown on
+/// the page, such as "$1,299.00" or "4.5 out of 5".
+pub fn deserialize_amount<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Amount {
+        Number(f64),
+        Text(String),
+    }
+
+    Ok(match Option::<Amount>::deserialize(deserializer)? {
+        Some(Amount::Number(number)) => Some(number),
+        Some(Amount::Text(text)) => parse_number(&text),
+        None => None,
+    })
+}
+
+/// Reads the first number in `text`, ignoring currency symbols, units and
+/// thousands separators. Text with more than a currency code before the
+/// number, such as "Out of stock since 2024", is not read as one.
+pub fn parse_number(text: &str) -> Option<f64> {
+    let start = text.find(|ch: char| ch.is_ascii_digit())?;
+    let prefix = &text[..start];
+
+    if prefix.chars().filter(|ch| ch.is_alphabetic()).count() > 3 {
+        return None;
+    }
+
+    let negative = prefix
+        .strip_suffix('-')
+        .is_some_and(|before| !before.ends_with(char::is_alphanumeric));
+
+    let number: &str = text[start..]
+        .split(|ch: char| !ch.is_ascii_digit() && ch != '.' && ch != ',')
+        .next()?
+        .trim_end_matches(['.', ',']);
+
+    let decimal_separator = decimal_separator(number);
+
+    let digits: String = number
+        .chars()
+        .filter_map(|ch| match ch {
+            '0'..='9' => Some(ch),
+            _ if Some(ch) == decimal_separator => Some('.'),
+            _ => None,
+        })
+        .collect();
+
+    let number: f64 = digits.parse().ok()?;
+
+    Some(if negative { -number } else { number })
+}
+
+/// Tells the decimal point from thousands separators. Where both '.' and
+/// ',' appear the last one is the decimal point, and a separator that
+/// repeats is never one. A single separator followed by exactly three
+/// digits, as in "1,299" or "1.299", groups thousands; otherwise it is the
+/// decimal point, as in "12,50" or "4.5".
+fn decimal_separator(number: &str) -> Option<char> {
+    let last = number.rfind(['.', ','])?;
+    let separator = number[last..].chars().next()?;
+    let other = if separator == '.' { ',' } else { '.' };
+
+    if number.contains(other) {
+        return Some(separator);
+    }
+
+    if number.matches(separator).count() > 1 {
+        return None;
+    }
+
+    let whole = &number[..last];
+    let fraction = &number[last + 1..];
+
+    if fraction.len() == 3 && !whole.trim_start_matches('0').is_empty() {
+        None
+    } else {
+        Some(separator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_prices_with_thousands_separators() {
+        assert_eq!(parse_number("$1,299.00"), Some(1299.0));
+        assert_eq!(parse_number("1,299"), Some(1299.0));
+        assert_eq!(parse_number("1.299 €"), Some(1299.0));
+        assert_eq!(parse_number("1.299.000"), Some(1_299_000.0));
+    }
+
+    #[test]
+    fn parses_decimal_commas() {
+        assert_eq!(parse_number("12,50 €"), Some(12.5));
+        assert_eq!(parse_number("€12,5"), Some(12.5));
+        assert_eq!(parse_number("1.299,00"), Some(1299.0));
+        assert_eq!(parse_number("EUR 0,125"), Some(0.125));
+    }
+
+    #[test]
+    fn parses_ratings() {
+        assert_eq!(parse_number("4.5 out of 5"), Some(4.5));
+        assert_eq!(parse_number("-3%"), Some(-3.0));
+    }
+
+    #[test]
+    fn rejects_text_that_only_contains_a_number() {
+        assert_eq!(parse_number("Out of stock since 2024"), None);
+        assert_eq!(parse_number("SKU-123"), Some(123.0));
+        assert_eq!(parse_number("Free"), None);
+    }
+
+    #[test]
+    fn deserializes_amounts_from_numbers_and_text() {
+        let product: ProductSummary = serde_json::from_str(
+            r#"{"name": "Lamp", "price": "$1,299.00", "rating": 4.5, "ratingScale": "5"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(product.price, Some(1299.0));
+        assert_eq!(product.rating, Some(4.5));
+        assert_eq!(product.rating_scale, Some(5.0));
+        assert_eq!(product.url, None);
+    }
+}
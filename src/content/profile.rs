@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "profile",
+  "type": "object",
+  "description": "A profile page presents a user, member or organization of a website. It typically shows their display name and handle, a short biography, statistics such as followers, karma or number of posts, links to their other pages and a list of their recent activity on the site.",
+  "properties": {
+    "displayName": {
+      "type": "string",
+      "description": "The name the user is shown with."
+    },
+    "handle": {
+      "type": "string",
+      "description": "The unique username or handle of the user, without any leading @."
+    },
+    "bio": {
+      "type": "string",
+      "description": "The biography or description the user wrote about themselves."
+    },
+    "stats": {
+      "type": "array",
+      "description": "Statistics shown on the profile, such as followers, karma or member since.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "label": {
+            "type": "string",
+            "description": "What the statistic measures."
+          },
+          "value": {
+            "type": "string",
+            "description": "The value of the statistic, as displayed."
+          }
+        }
+      }
+    },
+    "links": {
+      "type": "array",
+      "description": "Links from the profile to the user's websites, other accounts or related pages.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "label": {
+            "type": "string",
+            "description": "The text of the link."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL the link points to."
+          }
+        }
+      }
+    },
+    "recentActivity": {
+      "type": "array",
+      "description": "The user's recent submissions, comments, posts or other activity.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "title": {
+            "type": "string",
+            "description": "The title or a short description of the activity."
+          },
+          "summary": {
+            "type": "string",
+            "description": "An excerpt of the content, such as the text of a comment."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL of the activity."
+          },
+          "timestamp": {
+            "type": "string",
+            "format": "date-time",
+            "description": "When the activity took place."
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    pub display_name: Option<String>,
+    pub handle: Option<String>,
+    pub bio: Option<String>,
+    #[serde(default)]
+    pub stats: Vec<Stat>,
+    #[serde(default)]
+    pub links: Vec<ProfileLink>,
+    #[serde(default)]
+    pub recent_activity: Vec<Activity>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Stat {
+    pub label: Option<String>,
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProfileLink {
+    pub label: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Activity {
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    pub url: Option<String>,
+    pub timestamp: Option<String>,
+}
+
+impl Profile {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        let resolve = |url: &mut Option<String>| {
+            *url = url.as_deref().map(|url| resolve_url(base_url, url));
+        };
+
+        for link in &mut self.links {
+            resolve(&mut link.url);
+        }
+
+        for activity in &mut self.recent_activity {
+            resolve(&mut activity.url);
+        }
+    }
+}
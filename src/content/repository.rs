@@ -0,0 +1,284 @@
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "repository",
+  "type": "object",
+  "description": "A repository page is a page of a code hosting site such as GitHub, GitLab, Gitea or Sourcehut showing a source code repository: its home page, a directory listing, its README, or its list of commits or issues. It shows the name and owner of the repository, a description, how many stars it has, the languages used, the files at the current path, the README, and recent commits and issues.",
+  "properties": {
+    "name": {
+      "type": "string",
+      "description": "The name of the repository."
+    },
+    "owner": {
+      "type": "string",
+      "description": "The user or organization that owns the repository."
+    },
+    "ownerUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the owner's page."
+    },
+    "description": {
+      "type": "string",
+      "description": "The short description of the repository."
+    },
+    "stars": {
+      "type": "integer",
+      "description": "The number of stars, written out in full, such as 1200 for 1.2k."
+    },
+    "forks": {
+      "type": "integer",
+      "description": "The number of forks, written out in full."
+    },
+    "languages": {
+      "type": "array",
+      "description": "The languages used in the repository, with their share of the code.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "name": {
+            "type": "string",
+            "description": "The name of the language."
+          },
+          "percentage": {
+            "type": "number",
+            "description": "The share of the code written in the language, from 0 to 100."
+          }
+        }
+      }
+    },
+    "branch": {
+      "type": "string",
+      "description": "The branch or tag being shown."
+    },
+    "path": {
+      "type": "string",
+      "description": "The directory being shown, relative to the root of the repository. Empty for the root."
+    },
+    "files": {
+      "type": "array",
+      "description": "The entries of the file tree at the current path, in the order shown.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "name": {
+            "type": "string",
+            "description": "The name of the file or directory."
+          },
+          "kind": {
+            "type": "string",
+            "enum": ["file", "directory"],
+            "description": "Whether the entry is a file or a directory."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL of the file or directory."
+          },
+          "lastCommitMessage": {
+            "type": "string",
+            "description": "The message of the last commit that changed the entry."
+          },
+          "updated": {
+            "type": "string",
+            "description": "When the entry was last changed."
+          }
+        }
+      }
+    },
+    "readme": {
+      "type": "string",
+      "description": "The full text of the README, as plain text. Keep headings, lists and code blocks on their own lines."
+    },
+    "commits": {
+      "type": "array",
+      "description": "Recent commits, newest first.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "message": {
+            "type": "string",
+            "description": "The first line of the commit message."
+          },
+          "author": {
+            "type": "string",
+            "description": "The author of the commit."
+          },
+          "date": {
+            "type": "string",
+            "description": "When the commit was made."
+          },
+          "sha": {
+            "type": "string",
+            "description": "The commit hash, abbreviated or full."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL of the commit."
+          }
+        }
+      }
+    },
+    "issues": {
+      "type": "array",
+      "description": "Recent issues or pull requests.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "number": {
+            "type": "integer",
+            "description": "The number of the issue."
+          },
+          "title": {
+            "type": "string",
+            "description": "The title of the issue."
+          },
+          "state": {
+            "type": "string",
+            "description": "Whether the issue is open or closed."
+          },
+          "author": {
+            "type": "string",
+            "description": "Who opened the issue."
+          },
+          "date": {
+            "type": "string",
+            "description": "When the issue was opened."
+          },
+          "comments": {
+            "type": "integer",
+            "description": "The number of comments on the issue."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL of the issue."
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Repository {
+    pub name: Option<String>,
+    pub owner: Option<String>,
+    pub owner_url: Option<String>,
+    pub description: Option<String>,
+    pub stars: Option<u64>,
+    pub forks: Option<u64>,
+    #[serde(default)]
+    pub languages: Vec<Language>,
+    pub branch: Option<String>,
+    pub path: Option<String>,
+    #[serde(default)]
+    pub files: Vec<FileEntry>,
+    pub readme: Option<String>,
+    #[serde(default)]
+    pub commits: Vec<Commit>,
+    #[serde(default)]
+    pub issues: Vec<Issue>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Language {
+    pub name: Option<String>,
+    pub percentage: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FileEntry {
+    pub name: Option<String>,
+    pub kind: Option<String>,
+    pub url: Option<String>,
+    pub last_commit_message: Option<String>,
+    pub updated: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Commit {
+    pub message: Option<String>,
+    pub author: Option<String>,
+    pub date: Option<String>,
+    pub sha: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Issue {
+    pub number: Option<u64>,
+    pub title: Option<String>,
+    pub state: Option<String>,
+    pub author: Option<String>,
+    pub date: Option<String>,
+    pub comments: Option<u64>,
+    pub url: Option<String>,
+}
+
+impl Repository {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        let resolve = |url: &mut Option<String>| {
+            *url = url.as_deref().map(|url| resolve_url(base_url, url));
+        };
+
+        resolve(&mut self.owner_url);
+
+        for file in &mut self.files {
+            resolve(&mut file.url);
+        }
+
+        for commit in &mut self.commits {
+            resolve(&mut commit.url);
+        }
+
+        for issue in &mut self.issues {
+            resolve(&mut issue.url);
+        }
+    }
+
+    /// Directories first, then files, each in the order shown.
+    pub fn sorted_files(&self) -> Vec<&FileEntry> {
+        let (directories, files): (Vec<&FileEntry>, Vec<&FileEntry>) =
+            self.files.iter().partition(|file| file.is_directory());
+
+        directories.into_iter().chain(files).collect()
+    }
+}
+
+impl FileEntry {
+    pub fn is_directory(&self) -> bool {
+        self.kind
+            .as_deref()
+            .is_some_and(|kind| kind.eq_ignore_ascii_case("directory") || kind == "dir")
+    }
+}
+
+impl Issue {
+    pub fn is_open(&self) -> bool {
+        !self.state.as_deref().is_some_and(|state| {
+            state.to_lowercase().contains("closed") || state.to_lowercase().contains("merged")
+        })
+    }
+}
+
+/// Shortens counts the way code hosting sites do, such as `12.3k`.
+pub fn format_count(count: u64) -> String {
+    match count {
+        0..1_000 => count.to_string(),
+        1_000..1_000_000 => format!("{:.1}k", count as f64 / 1_000.0),
+        _ => format!("{:.1}M", count as f64 / 1_000_000.0),
+    }
+}
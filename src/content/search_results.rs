@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+
+use crate::content::paginated::Paginated;
+use crate::prelude::*;
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "search_results",
+  "type": "object",
+  "description": "A search results page lists documents matching a query submitted to a search engine or to the search feature of a website. Each result typically shows a title linking to the document, the address of the document and a snippet of text in which the query terms appear. The page often reports how many results were found and suggests related queries.",
+  "properties": {
+    "query": {
+      "type": "string",
+      "description": "The query the results were returned for."
+    },
+    "resultCount": {
+      "type": "string",
+      "description": "The number of results reported by the page, as displayed."
+    },
+    "results": {
+      "type": "array",
+      "description": "The search results on the page, in the order they are shown.",
+      "items": {
+        "type": "object",
+        "description": "A search result object",
+        "properties": {
+          "title": {
+            "type": "string",
+            "description": "The title of the result."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL the result links to."
+          },
+          "snippet": {
+            "type": "string",
+            "description": "The excerpt of the document shown beneath the title."
+          },
+          "displayedUrl": {
+            "type": "string",
+            "description": "The address or breadcrumb path shown for the result, as displayed."
+          }
+        }
+      }
+    },
+    "relatedQueries": {
+      "type": "array",
+      "description": "Related or suggested searches shown on the page.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "query": {
+            "type": "string",
+            "description": "The text of the related query."
+          },
+          "url": {
+            "type": "string",
+            "format": "uri",
+            "description": "The URL that performs the related search."
+          }
+        }
+      }
+    },
+    "nextPageUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the next page of results, if any."
+    },
+    "previousPageUrl": {
+      "type": "string",
+      "format": "uri",
+      "description": "The URL of the previous page of results, if any."
+    }
+  }
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResults {
+    pub query: Option<String>,
+    pub result_count: Option<String>,
+    #[serde(default)]
+    pub results: Vec<SearchResult>,
+    #[serde(default)]
+    pub related_queries: Vec<RelatedQuery>,
+    #[serde(default)]
+    pub next_page_url: Option<String>,
+    #[serde(default)]
+    pub previous_page_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub snippet: Option<String>,
+    pub displayed_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RelatedQuery {
+    pub query: Option<String>,
+    pub url: Option<String>,
+}
+
+impl SearchResults {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    /// Makes result, related query and page links absolute. Site-search
+    /// pages in particular tend to use relative links.
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        let resolve = |url: &mut Option<String>| {
+            *url = url.as_deref().map(|url| resolve_url(base_url, url));
+        };
+
+        for result in &mut self.results {
+            resolve(&mut result.url);
+        }
+
+        for related_query in &mut self.related_queries {
+            resolve(&mut related_query.url);
+        }
+
+        resolve(&mut self.next_page_url);
+        resolve(&mut self.previous_page_url);
+    }
+
+    /// Lowercased terms of the query, used to highlight matches in snippets.
+    pub fn query_terms(&self) -> Vec<String> {
+        self.query
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|term| {
+                term.trim_matches(|ch: char| !ch.is_alphanumeric())
+                    .to_lowercase()
+            })
+            .filter(|term| term.chars().count() > 1)
+            .collect()
+    }
+}
+
+impl Paginated for SearchResults {
+    type Item = SearchResult;
+
+    fn items_mut(&mut self) -> &mut Vec<SearchResult> {
+        &mut self.results
+    }
+
+    fn set_next_page_url(&mut self, next_page_url: Option<String>) {
+        self.next_page_url = next_page_url;
+    }
+
+    fn into_items(self) -> (Vec<SearchResult>, Option<String>) {
+        (self.results, self.next_page_url)
+    }
+
+    fn item_key(item: &SearchResult) -> Option<String> {
+        item.url.clone()
+    }
+}
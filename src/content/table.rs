@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+use crate::content::product_list::parse_number;
+use crate::prelude::*;
+
+pub const JSON_SCHEMA: &str = r#"
+{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "title": "table",
+  "type": "object",
+  "description": "A table is a page whose main content is tabular data, such as a ranking, a league table, a status page, a price sheet or a comparison chart. It has column headers and rows of cells, and some cells link to other pages.",
+  "properties": {
+    "caption": {
+      "type": "string",
+      "description": "The caption or title of the table."
+    },
+    "columns": {
+      "type": "array",
+      "description": "The column headers, from left to right.",
+      "items": {
+        "type": "string"
+      }
+    },
+    "rows": {
+      "type": "array",
+      "description": "The rows of the table, from top to bottom, without the header row.",
+      "items": {
+        "type": "object",
+        "properties": {
+          "cells": {
+            "type": "array",
+            "description": "The cells of the row, in the same order as the columns.",
+            "items": {
+              "type": "object",
+              "properties": {
+                "text": {
+                  "type": "string",
+                  "description": "The text of the cell."
+                },
+                "url": {
+                  "type": "string",
+                  "format": "uri",
+                  "description": "The URL the cell links to, if any."
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+  },
+  "required": ["rows"]
+}
+"#;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Table {
+    pub caption: Option<String>,
+    #[serde(default)]
+    pub columns: Vec<String>,
+    #[serde(default)]
+    pub rows: Vec<TableRow>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TableRow {
+    #[serde(default)]
+    pub cells: Vec<TableCell>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TableCell {
+    pub text: Option<String>,
+    pub url: Option<String>,
+}
+
+impl TableRow {
+    pub fn cell_text(&self, column: usize) -> &str {
+        self.cells
+            .get(column)
+            .and_then(|cell| cell.text.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+impl Table {
+    pub fn get_json_schema() -> &'static str {
+        JSON_SCHEMA
+    }
+
+    pub fn resolve_urls(&mut self, base_url: &str) {
+        for row in &mut self.rows {
+            for cell in &mut row.cells {
+                cell.url = cell.url.as_deref().map(|url| resolve_url(base_url, url));
+            }
+        }
+    }
+
+    /// Number of columns, counting cells of rows that are wider than the
+    /// header.
+    pub fn column_count(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|row| row.cells.len())
+            .chain(std::iter::once(self.columns.len()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Indices of the rows sorted on `column`. Cells that read as numbers
+    /// come first in numeric order, followed by the others in text order;
+    /// empty cells are kept at the end in their listed order.
+    pub fn sorted_indices(&self, column: usize, descending: bool) -> Vec<usize> {
+        let keys: Vec<(bool, Option<f64>, String)> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let text = row.cell_text(column).trim();
+                (text.is_empty(), numeric_value(text), text.to_lowercase())
+            })
+            .collect();
+
+        let mut indices: Vec<usize> = (0..self.rows.len()).collect();
+
+        indices.sort_by(|a, b| {
+            let (a_empty, a_number, a_text) = &keys[*a];
+            let (b_empty, b_number, b_text) = &keys[*b];
+
+            a_empty.cmp(b_empty).then_with(|| {
+                let ordering = match (a_number, b_number) {
+                    (Some(a), Some(b)) => a.total_cmp(b),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+                .then_with(|| a_text.cmp(b_text));
+
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            })
+        });
+
+        indices
+    }
+
+    /// Serializes the header and the given rows as delimiter-separated
+    /// values: RFC 4180 quoting for commas, and tabs and line breaks
+    /// replaced by spaces otherwise.
+    pub fn to_delimited(&self, indices: &[usize], delimiter: char) -> String {
+        let column_count = self.column_count();
+
+        let header = (0..column_count).map(|column| {
+            self.columns
+                .get(column)
+                .map(String::as_str)
+                .unwrap_or_default()
+        });
+
+        let mut lines = vec![join_fields(header, delimiter)];
+
+        for index in indices {
+            let row = &self.rows[*index];
+            let fields = (0..column_count).map(|column| row.cell_text(column));
+            lines.push(join_fields(fields, delimiter));
+        }
+
+        lines.join("\r\n") + "\r\n"
+    }
+}
+
+/// Reads cells such as "1,024", "$12.50" or "-3%" as numbers, but not cells
+/// that merely contain a number, such as "Version 2".
+fn numeric_value(text: &str) -> Option<f64> {
+    let first = text.chars().next()?;
+
+    if first.is_ascii_digit() || "+-.$€£¥(".contains(first) {
+        parse_number(text)
+    } else {
+        None
+    }
+}
+
+fn join_fields<'a>(fields: impl Iterator<Item = &'a str>, delimiter: char) -> String {
+    fields
+        .map(|field| {
+            if delimiter != ',' {
+                field.replace(['\t', '\r', '\n'], " ")
+            } else if field.contains([',', '"', '\r', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(&delimiter.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(columns: &[&str], rows: &[&[&str]]) -> Table {
+        Table {
+            caption: None,
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+            rows: rows
+                .iter()
+                .map(|cells| TableRow {
+                    cells: cells
+                        .iter()
+                        .map(|text| TableCell {
+                            text: Some(text.to_string()),
+                            url: None,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn sorts_numbers_before_text_and_empty_cells_last() {
+        let table = table(&["Price"], &[&["5"], &["Free"], &[""], &["€1"], &["1,024"]]);
+
+        assert_eq!(table.sorted_indices(0, false), vec![3, 0, 4, 1, 2]);
+        assert_eq!(table.sorted_indices(0, true), vec![1, 4, 0, 3, 2]);
+    }
+
+    #[test]
+    fn keeps_listed_order_of_equal_cells() {
+        let table = table(&["Name"], &[&["b"], &["A"], &["a"], &[""], &[""]]);
+
+        assert_eq!(table.sorted_indices(0, false), vec![1, 2, 0, 3, 4]);
+    }
+
+    #[test]
+    fn quotes_comma_separated_fields() {
+        let table = table(
+            &["Name", "Note"],
+            &[&["Smith, J.", "said \"hi\""], &["Plain"]],
+        );
+
+        assert_eq!(
+            table.to_delimited(&[0, 1], ','),
+            "Name,Note\r\n\"Smith, J.\",\"said \"\"hi\"\"\"\r\nPlain,\r\n"
+        );
+    }
+
+    #[test]
+    fn replaces_tabs_and_line_breaks_in_tab_separated_fields() {
+        let table = table(&["Name", "Note"], &[&["a\tb", "line\nbreak"]]);
+
+        assert_eq!(
+            table.to_delimited(&[0], '\t'),
+            "Name\tNote\r\na b\tline break\r\n"
+        );
+    }
+}
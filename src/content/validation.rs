@@ -0,0 +1,235 @@
+use serde_json::{Map, Number, Value};
+use std::fmt;
+
+/// A problem found at one location of the translated data.
+#[derive(Clone, Debug)]
+pub struct FieldProblem {
+    pub path: String,
+    pub message: String,
+    /// Whether the problem makes the data unusable rather than just
+    /// losing the value of one field.
+    pub structural: bool,
+}
+
+impl fmt::Display for FieldProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ValidationReport {
+    pub coercions: Vec<String>,
+    pub problems: Vec<FieldProblem>,
+}
+
+impl ValidationReport {
+    pub fn is_structurally_invalid(&self) -> bool {
+        self.problems.iter().any(|problem| problem.structural)
+    }
+
+    fn coerced(&mut self, path: &str, message: &str) {
+        self.coercions.push(format!("{}: {}", path, message));
+    }
+
+    fn problem(&mut self, path: &str, message: String, structural: bool) {
+        self.problems.push(FieldProblem {
+            path: path.to_string(),
+            message,
+            structural,
+        });
+    }
+}
+
+/// Checks `data` against `schema`, coercing common mismatches in place:
+/// scalars of the wrong type, object keys that differ from the schema only
+/// in case or separators, and single objects where an array is expected.
+/// Fields that cannot be coerced are cleared and reported.
+pub fn validate(schema: &Value, data: &mut Value) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    validate_value(schema, data, "$", &mut report);
+    report
+}
+
+fn validate_value(schema: &Value, data: &mut Value, path: &str, report: &mut ValidationReport) {
+    let types = schema_types(schema);
+
+    if types.is_empty() || data.is_null() {
+        return;
+    }
+
+    if types.contains(&"object") {
+        match data {
+            Value::Object(obj) => validate_object(schema, obj, path, report),
+            _ => report.problem(
+                path,
+                format!("expected an object, found {}", type_name(data)),
+                true,
+            ),
+        }
+    } else if types.contains(&"array") {
+        if data.is_object() {
+            *data = Value::Array(vec![data.take()]);
+            report.coerced(path, "wrapped object in an array");
+        }
+
+        match data {
+            Value::Array(items) => {
+                let item_schema = schema.get("items").cloned().unwrap_or_default();
+
+                for (index, item) in items.iter_mut().enumerate() {
+                    validate_value(&item_schema, item, &format!("{}[{}]", path, index), report);
+                }
+            }
+            _ => report.problem(
+                path,
+                format!("expected an array, found {}", type_name(data)),
+                true,
+            ),
+        }
+    } else if !has_type(&types, data) {
+        match coerce_scalar(&types, data) {
+            Some(coerced) => {
+                report.coerced(
+                    path,
+                    &format!("converted {} to {}", type_name(data), type_name(&coerced)),
+                );
+                *data = coerced;
+            }
+            None => {
+                report.problem(
+                    path,
+                    format!("expected {}, found {}", types.join(" or "), type_name(data)),
+                    false,
+                );
+                *data = Value::Null;
+            }
+        }
+    }
+}
+
+fn validate_object(
+    schema: &Value,
+    obj: &mut Map<String, Value>,
+    path: &str,
+    report: &mut ValidationReport,
+) {
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return;
+    };
+
+    let unknown_keys: Vec<String> = obj
+        .keys()
+        .filter(|key| !properties.contains_key(*key))
+        .cloned()
+        .collect();
+
+    for key in unknown_keys {
+        if let Some(property) = properties
+            .keys()
+            .find(|property| normalize_key(property) == normalize_key(&key))
+            && !obj.contains_key(property)
+            && let Some(value) = obj.remove(&key)
+        {
+            report.coerced(
+                &format!("{}.{}", path, key),
+                &format!("renamed to {}", property),
+            );
+            obj.insert(property.clone(), value);
+        }
+    }
+
+    for (key, property_schema) in properties {
+        if let Some(value) = obj.get_mut(key) {
+            validate_value(property_schema, value, &format!("{}.{}", path, key), report);
+        }
+    }
+
+    let required = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str);
+
+    for key in required {
+        if obj.get(key).is_none_or(Value::is_null) {
+            report.problem(
+                &format!("{}.{}", path, key),
+                "required field is missing".to_string(),
+                true,
+            );
+        }
+    }
+}
+
+fn coerce_scalar(types: &[&str], data: &Value) -> Option<Value> {
+    for expected in types {
+        let coerced = match (*expected, data) {
+            ("string", Value::Number(number)) => Some(Value::String(number.to_string())),
+            ("string", Value::Bool(flag)) => Some(Value::String(flag.to_string())),
+            ("integer", Value::String(text)) => text
+                .trim()
+                .replace(',', "")
+                .parse::<i64>()
+                .ok()
+                .map(Value::from),
+            ("number", Value::String(text)) => text
+                .trim()
+                .replace(',', "")
+                .parse::<f64>()
+                .ok()
+                .and_then(Number::from_f64)
+                .map(Value::Number),
+            ("integer", Value::Number(number)) => number
+                .as_f64()
+                .map(|number| Value::from(number.round() as i64)),
+            ("boolean", Value::String(text)) => match text.trim().to_lowercase().as_str() {
+                "true" | "yes" => Some(Value::Bool(true)),
+                "false" | "no" => Some(Value::Bool(false)),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if coerced.is_some() {
+            return coerced;
+        }
+    }
+
+    None
+}
+
+fn schema_types(schema: &Value) -> Vec<&str> {
+    match schema.get("type") {
+        Some(Value::String(name)) => vec![name.as_str()],
+        Some(Value::Array(names)) => names.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn has_type(types: &[&str], value: &Value) -> bool {
+    let name = type_name(value);
+
+    types.contains(&name) || (name == "integer" && types.contains(&"number"))
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Key used to match `discussion_url`, `discussionUrl` and `discussion-url`.
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|ch| *ch != '_' && *ch != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
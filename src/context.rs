@@ -1,7 +1,7 @@
 use std::fs;
 use std::env;
 use std::path::Path;
-use headless_chrome::Browser;
+use std::process::Stdio;
 use parversion::prelude::{ExecutionContext, Metadata, Options};
 use parversion::provider::sqlite::SqliteProvider;
 use parversion::translation;
@@ -10,27 +10,47 @@ use parversion::document_format::DocumentFormat;
 use parversion::document::{DocumentType, DocumentRole};
 use std::sync::Arc;
 
-use crate::content::{Content, ContentPayload, ContentType};
+use crate::browser::{TabPool, TabSlot};
+use crate::config::Config;
+use crate::content::{Content, ContentPayload, ContentType, Translated};
+use crate::fetch::{apply_request_settings, wait_for_content};
+use crate::history::History;
+use crate::line_editor::LineEditor;
 use crate::prelude::*;
+use crate::snapshots::save_snapshot;
+
+const DEFAULT_MEDIA_PLAYER: &str = "mpv";
 
 #[derive(Clone)]
 pub struct Context {
-    browser: Browser,
+    tabs: TabPool,
     provider: Arc<SqliteProvider>,
+    config: Arc<Config>,
+    history: History,
+    input: LineEditor,
     url: Option<String>,
+    content_type: Option<ContentType>,
     mode: Mode,
 }
 
 impl Context {
-    pub fn new(provider: Arc<SqliteProvider>, browser: Browser) -> Self {
+    pub fn new(provider: Arc<SqliteProvider>, tabs: TabPool, config: Arc<Config>) -> Self {
         Context {
-            browser,
+            tabs,
             provider,
+            config,
+            history: History::load(),
+            input: LineEditor::new(),
             url: None,
+            content_type: None,
             mode: Mode::NavigationInput,
         }
     }
 
+    pub fn get_config(&self) -> &Config {
+        &self.config
+    }
+
     pub fn get_url(&self) -> Option<String> {
         self.url.clone()
     }
@@ -51,27 +71,77 @@ impl Context {
         }
     }
 
-    pub fn append_char(&mut self, ch: char) {
-        if self.url.is_none() {
-            self.url = Some(String::new());
-        }
+    pub fn input(&self) -> &LineEditor {
+        &self.input
+    }
 
-        if let Some(url) = &mut self.url {
-            url.push(ch);
-        }
+    pub fn input_mut(&mut self) -> &mut LineEditor {
+        &mut self.input
+    }
+
+    pub fn history_previous(&mut self) {
+        self.input.history_previous(self.history.entries());
     }
 
-    pub fn remove_last_char(&mut self) {
-        if let Some(url) = &mut self.url {
-            url.pop();
+    pub fn history_next(&mut self) {
+        self.input.history_next(self.history.entries());
+    }
+
+    pub fn complete_input(&mut self) {
+        let candidates: Vec<String> = self
+            .config
+            .bookmarks
+            .iter()
+            .chain(self.history.entries().iter().rev())
+            .cloned()
+            .collect();
+
+        self.input.complete(&candidates);
+    }
+
+    pub fn submit_input(&mut self) {
+        let input = self.input.text();
+        self.content_type = None;
+
+        match expand_navigation_input(&input, &self.config.search_keywords) {
+            Some(url) => {
+                self.history.record(&url);
+                self.input.set_text(&url);
+                self.url = Some(url);
+            }
+            None => {
+                log::warn!("Could not expand navigation input: {}", input);
+                self.url = Some(input.trim().to_string());
+            }
         }
     }
 
+    /// Points the context at a link followed from the current page.
+    pub fn follow_link(&mut self, url: String) {
+        self.content_type = None;
+        self.history.record(&url);
+        self.input.set_text(&url);
+        self.url = Some(url);
+    }
+
+    /// Like `follow_link`, but opens the page as `content_type` without
+    /// detecting it.
+    pub fn follow_link_as(&mut self, url: String, content_type: ContentType) {
+        self.follow_link(url);
+        self.content_type = Some(content_type);
+    }
+
     pub fn get_mode(&self) -> &Mode {
         &self.mode
     }
 
     pub fn set_mode(&mut self, mode: Mode) {
+        if let Mode::NavigationInput = mode
+            && !matches!(self.mode, Mode::NavigationInput)
+        {
+            self.input.set_text(&self.url_to_string());
+        }
+
         self.mode = mode;
     }
 
@@ -79,14 +149,50 @@ impl Context {
         let _ = std::process::Command::new("open").arg(&url).spawn();
     }
 
+    /// Starts the configured media player on `url`, detached from the
+    /// terminal so that it does not draw over the interface.
+    pub fn play_media(&self, url: &str) -> Result<(), Errors> {
+        let command = self
+            .config
+            .media_player
+            .as_deref()
+            .unwrap_or(DEFAULT_MEDIA_PLAYER);
+
+        let mut parts: Vec<String> = command.split_whitespace().map(str::to_string).collect();
+
+        if parts.is_empty() {
+            return Err(Errors::ConfigError("Media player command is empty".into()));
+        }
+
+        if parts.iter().any(|part| part.contains("{}")) {
+            for part in &mut parts {
+                *part = part.replace("{}", url);
+            }
+        } else {
+            parts.push(url.to_string());
+        }
+
+        std::process::Command::new(&parts[0])
+            .args(&parts[1..])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| {
+                Errors::UnexpectedError(format!("Could not start {}: {}", parts[0], e))
+            })?;
+
+        Ok(())
+    }
+
     pub async fn open(
         &self,
         execution_context: Arc<ExecutionContext>,
         regenerate: bool,
-    ) -> Result<ContentPayload, Errors> {
+    ) -> Result<Translated, Errors> {
         log::trace!("In open");
 
-        let document = self.fetch_document()?;
+        let document = self.fetch_document().await?;
         let url = self
             .get_url()
             .ok_or_else(|| Errors::UnexpectedError("URL not found".into()))?;
@@ -114,73 +220,179 @@ impl Context {
 
         let document_format = DocumentFormat::default();
 
-        let normalized_document = normalization::normalize_text_to_document(
+        let content_type = match self.content_type_override() {
+            Some(content_type) => {
+                log::info!("Using content type override: {}", content_type.as_str());
+                content_type
+            }
+            None => {
+                self.detect_content_type(
+                    &document,
+                    &options,
+                    &metadata,
+                    &document_format,
+                    execution_context.clone(),
+                )
+                .await?
+            }
+        };
+
+        let translated = match self
+            .translate(&document, &url, &content_type, &options, execution_context.clone())
+            .await
+        {
+            Err(Errors::ValidationError(problems)) if !regenerate => {
+                log::warn!(
+                    "Translated content is invalid, regenerating: {}",
+                    problems.join("; ")
+                );
+
+                let options = Options {
+                    regenerate: true,
+                    ..options
+                };
+
+                self.translate(&document, &url, &content_type, &options, execution_context)
+                    .await?
+            }
+            result => result?,
+        };
+
+        Ok(translated)
+    }
+
+    async fn translate(
+        &self,
+        document: &str,
+        url: &str,
+        content_type: &ContentType,
+        options: &Options,
+        execution_context: Arc<ExecutionContext>,
+    ) -> Result<Translated, Errors> {
+        let json_schema = Content::get_json_schema_by_content_type(content_type);
+
+        let metadata = Metadata {
+            document_type: Some(DocumentType::Html),
+            origin: url.to_string(),
+            role: DocumentRole::Instance,
+        };
+
+        let schema_metadata = Metadata {
+            document_type: Some(DocumentType::Json),
+            origin: url.to_string(),
+            role: DocumentRole::Schema,
+        };
+
+        let translated_document = translation::translate_text_to_document(
             self.provider.clone(),
-            document.clone(),
-            &options,
-            &metadata,
-            &document_format,
-            execution_context.clone()
+            (document.to_string(), &metadata),
+            (json_schema, &schema_metadata),
+            options,
+            &DocumentFormat::default(),
+            execution_context,
         )
         .await
         .map_err(|e| {
-            Errors::NormalizationError(format!("Could not normalize paage: {:?}", e))
+            Errors::TranslationError(format!("Could not translate content: {:?}", e))
         })?;
 
         #[cfg(debug_assertions)]
         {
-            self.debug_document(&url, "normalized.json", &normalized_document.to_string());
+            self.debug_document(url, "translated.json", &translated_document.to_string());
         }
 
+        let mut translated = Content::content_data_to_payload(content_type, &translated_document.data)?;
 
+        match &mut translated.payload {
+            ContentPayload::Digest(digest) => {
+                digest.resolve_urls(url);
 
-        let content_type: Option<ContentType> = Content::match_content_names(normalized_document.metadata.semantic_content_types.unwrap());
+                match serde_json::to_string(digest) {
+                    Ok(data) => save_snapshot(url, &data),
+                    Err(e) => log::warn!("Could not serialize digest: {}", e),
+                }
+            }
+            ContentPayload::SearchResults(search_results) => search_results.resolve_urls(url),
+            ContentPayload::ProductList(product_list) => product_list.resolve_urls(url),
+            ContentPayload::Product(product) => product.resolve_urls(url),
+            ContentPayload::Profile(profile) => profile.resolve_urls(url),
+            ContentPayload::ForumIndex(forum_index) => forum_index.resolve_urls(url),
+            ContentPayload::ForumThread(forum_thread) => forum_thread.resolve_urls(url),
+            ContentPayload::EventList(event_list) => event_list.resolve_urls(url),
+            ContentPayload::Documentation(documentation) => documentation.resolve_urls(url),
+            ContentPayload::Table(table) => table.resolve_urls(url),
+            ContentPayload::Media(media) => media.resolve_urls(url),
+            ContentPayload::JobList(job_list) => job_list.resolve_urls(url),
+            ContentPayload::JobPosting(job_posting) => job_posting.resolve_urls(url),
+            ContentPayload::Repository(repository) => repository.resolve_urls(url),
+            ContentPayload::Custom(_) => {}
+        }
 
-        if let Some(content_type) = content_type {
-            let json_schema = Content::get_json_schema_by_content_type(&content_type);
+        Ok(translated)
+    }
 
-            let options = Options {
-                origin: Some(url.clone()),
-                date: None,
-                regenerate,
-            };
+    async fn detect_content_type(
+        &self,
+        document: &str,
+        options: &Options,
+        metadata: &Metadata,
+        document_format: &DocumentFormat,
+        execution_context: Arc<ExecutionContext>,
+    ) -> Result<ContentType, Errors> {
+        let normalized_document = normalization::normalize_text_to_document(
+            self.provider.clone(),
+            document.to_string(),
+            options,
+            metadata,
+            document_format,
+            execution_context,
+        )
+        .await
+        .map_err(|e| {
+            Errors::NormalizationError(format!("Could not normalize paage: {:?}", e))
+        })?;
+
+        #[cfg(debug_assertions)]
+        {
+            self.debug_document(&metadata.origin, "normalized.json", &normalized_document.to_string());
+        }
 
-            let schema_metadata = Metadata {
-                document_type: Some(DocumentType::Json),
-                origin: url.clone(),
-                role: DocumentRole::Schema,
-            };
+        let Some(content_names) = normalized_document.metadata.semantic_content_types else {
+            return Err(Errors::UnexpectedContentType(
+                "No semantic content types were detected".to_string(),
+            ));
+        };
 
-            let translated_document = translation::translate_text_to_document(
-                self.provider.clone(),
-                (document, &metadata),
-                (json_schema.to_string(), &schema_metadata),
-                &options,
-                &document_format,
-                execution_context.clone(),
+        Content::match_content_names(&self.config, content_names).ok_or_else(|| {
+            Errors::UnexpectedContentType(
+                "Could not match content type with any of expected types".to_string(),
             )
-            .await
-            .map_err(|e| {
-                Errors::TranslationError(format!("Could not translate content: {:?}", e))
-            })?;
+        })
+    }
 
-            #[cfg(debug_assertions)]
-            {
-                self.debug_document(&url, "translated.json", &translated_document.to_string());
-            }
+    pub fn remember_content_type(&self, content_type: &ContentType) {
+        Content::remember_content_type_override(&self.url_to_string(), content_type);
+    }
 
-            let payload = Content::content_data_to_payload(&content_type, &translated_document.data)
-                .expect("Could not deserialize translated content");
+    /// Content type the current page is opened as without detecting it.
+    pub fn content_type_override(&self) -> Option<ContentType> {
+        self.content_type
+            .clone()
+            .or_else(|| Content::get_content_type_override(&self.config, &self.url_to_string()))
+    }
 
-            Ok(payload)
-        } else {
-            Err(Errors::UnexpectedContentType(
-                "Could not match content type with any of expected types".to_string(),
-            ))
-        }
+    /// Drops the content type forced for the current page, returning whether
+    /// there was one that is not set in the config.
+    pub fn forget_content_type(&mut self) -> bool {
+        let followed_as = self.content_type.take().is_some();
+        let remembered = Content::forget_content_type_override(&self.url_to_string());
+
+        followed_as || remembered
     }
 
-    fn fetch_document(&self) -> Result<String, Errors> {
+    /// Fetches the page on the blocking thread pool, as driving the browser
+    /// waits on it synchronously.
+    async fn fetch_document(&self) -> Result<String, Errors> {
         let url = self
             .get_url()
             .ok_or_else(|| Errors::UnexpectedError("URL not found".into()))?;
@@ -190,36 +402,59 @@ impl Context {
             return Err(Errors::InvalidUrl);
         }
 
-        let tab = self
-            .browser
-            .new_tab()
-            .map_err(|e| Errors::BrowserError(format!("Could not create new tab: {}", e)))?;
+        let slot = self.tabs.reserve().await;
+        let config = Arc::clone(&self.config);
+
+        tokio::task::spawn_blocking(move || fetch_document(slot, &config, url))
+            .await
+            .map_err(|e| Errors::UnexpectedError(format!("Fetch task failed: {}", e)))?
+    }
+
+    fn debug_document(&self, dir_name: &str, name: &str, content: &str) {
+        let debug_subdir = to_safe_dir_name(&dir_name);
+        let path = env::current_dir().expect("Could not getting current directory");
+        let path = path.join("debug").join(debug_subdir);
+        fs::create_dir_all(&path).expect("Could not create directory");
+
+        let input_document_path = Path::new(&path).join(name);
+        fs::write(input_document_path, content).expect("Could not write to file");
+    }
+}
+
+fn fetch_document(slot: TabSlot, config: &Config, url: String) -> Result<String, Errors> {
+    let settings = config.fetch_settings_for(&url);
+    let profile = settings.request_profile();
+    let mut tab = slot.acquire(&profile)?;
+
+    if let Err(e) = apply_request_settings(&tab, &settings) {
+        tab.discard_after_use();
+        return Err(e);
+    }
+
+    tab.set_request_profile(profile);
 
+    tab.run_with_timeout(settings.navigation_timeout(), "Navigation", move |tab| {
         tab.navigate_to(&url)
             .map_err(|e| Errors::BrowserError(format!("Could not navigate: {}", e)))?;
 
         tab.wait_until_navigated()
             .map_err(|e| Errors::BrowserError(format!("Could not wait: {}", e)))?;
 
-        let document = tab
+        Ok(())
+    })?;
+
+    wait_for_content(&tab, &settings)?;
+
+    let document = tab.run_with_timeout(settings.evaluation_timeout(), "Page capture", |tab| {
+        Ok(tab
             .evaluate("document.documentElement.outerHTML", false)
             .map_err(|e| Errors::BrowserError(format!("Could not evaluate JavaScript: {}", e)))?
             .value
             .ok_or_else(|| Errors::BrowserError("No content returned".into()))?
             .as_str()
             .ok_or_else(|| Errors::BrowserError("Content is not a string".into()))?
-            .to_string();
+            .to_string())
+    })?;
 
-        Ok(document)
-    }
-
-    fn debug_document(&self, dir_name: &str, name: &str, content: &str) {
-        let debug_subdir = to_safe_dir_name(&dir_name);
-        let path = env::current_dir().expect("Could not getting current directory");
-        let path = path.join("debug").join(debug_subdir);
-        fs::create_dir_all(&path).expect("Could not create directory");
-
-        let input_document_path = Path::new(&path).join(name);
-        fs::write(input_document_path, content).expect("Could not write to file");
-    }
+    Ok(document)
 }
@@ -0,0 +1,118 @@
+use headless_chrome::Tab;
+use headless_chrome::protocol::cdp::Network::{Cookie, CookieParam, GetAllCookies};
+use serde_json::{Value, json};
+use std::fs;
+use std::path::Path;
+
+use crate::prelude::*;
+use crate::storage;
+
+const SESSION_COOKIES_FILE_NAME: &str = "session_cookies.json";
+const HTTP_ONLY_PREFIX: &str = "#HttpOnly_";
+
+/// Cookies to install in every new tab: those imported from a cookies.txt
+/// file followed by any saved with `pori login`.
+pub fn load_cookies(cookies_file: Option<&Path>) -> Vec<CookieParam> {
+    let mut cookies = Vec::new();
+
+    if let Some(path) = cookies_file {
+        match fs::read_to_string(path) {
+            Ok(text) => cookies.extend(parse_netscape_cookies(&text)),
+            Err(e) => log::warn!("Could not read cookies file {}: {}", path.display(), e),
+        }
+    }
+
+    let saved: Vec<Value> = storage::load(SESSION_COOKIES_FILE_NAME);
+    cookies.extend(saved.into_iter().filter_map(to_cookie_param));
+
+    log::debug!("Loaded {} cookies", cookies.len());
+
+    cookies
+}
+
+/// Every cookie in the browser session, not only those for the tab's
+/// current URL, so that logins spanning several domains are kept.
+pub fn read_all_cookies(tab: &Tab) -> Result<Vec<Cookie>, Errors> {
+    tab.call_method(GetAllCookies(None))
+        .map(|result| result.cookies)
+        .map_err(|e| Errors::BrowserError(format!("Could not read cookies: {}", e)))
+}
+
+pub fn save_session_cookies(cookies: &[Cookie]) -> Result<(), Errors> {
+    let mut saved: Vec<Value> = storage::load(SESSION_COOKIES_FILE_NAME);
+
+    for cookie in cookies {
+        let value = serde_json::to_value(cookie)
+            .map_err(|e| Errors::StorageError(format!("Could not serialize cookie: {}", e)))?;
+
+        saved.retain(|existing| cookie_key(existing) != cookie_key(&value));
+        saved.push(value);
+    }
+
+    storage::save(SESSION_COOKIES_FILE_NAME, &saved)
+}
+
+/// Parses the Netscape cookies.txt format exported by browsers and curl.
+pub fn parse_netscape_cookies(text: &str) -> Vec<CookieParam> {
+    text.lines()
+        .filter_map(|line| {
+            let (line, http_only) = match line.strip_prefix(HTTP_ONLY_PREFIX) {
+                Some(rest) => (rest, true),
+                None => (line, false),
+            };
+
+            if line.trim().is_empty() || line.starts_with('#') {
+                return None;
+            }
+
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [domain, _, path, secure, expires, name, value] = fields[..] else {
+                log::warn!("Skipping malformed cookie line: {}", line);
+                return None;
+            };
+
+            let mut cookie = json!({
+                "name": name,
+                "value": value,
+                "domain": domain,
+                "path": path,
+                "secure": secure.eq_ignore_ascii_case("TRUE"),
+                "httpOnly": http_only,
+            });
+
+            if let Ok(expires) = expires.parse::<f64>()
+                && expires > 0.0
+            {
+                cookie["expires"] = json!(expires);
+            }
+
+            to_cookie_param(cookie)
+        })
+        .collect()
+}
+
+/// Converts a saved or parsed cookie. Chrome reports session cookies with
+/// an expiry of -1, which would install them as already expired, so the
+/// expiry is left out for those.
+fn to_cookie_param(mut value: Value) -> Option<CookieParam> {
+    if let Some(cookie) = value.as_object_mut()
+        && cookie
+            .get("expires")
+            .and_then(Value::as_f64)
+            .is_some_and(|expires| expires <= 0.0)
+    {
+        cookie.remove("expires");
+    }
+
+    serde_json::from_value(value)
+        .map_err(|e| log::warn!("Could not convert cookie: {}", e))
+        .ok()
+}
+
+fn cookie_key(value: &Value) -> (Option<&str>, Option<&str>, Option<&str>) {
+    (
+        value["name"].as_str(),
+        value["domain"].as_str(),
+        value["path"].as_str(),
+    )
+}
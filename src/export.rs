@@ -0,0 +1,50 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::constants::PROGRAM_NAME;
+use crate::prelude::*;
+
+const EXPORTS_DIR_NAME: &str = "exports";
+
+/// Files exported from a page go to the downloads directory, or to the data
+/// directory where there is none.
+fn export_dir() -> Option<PathBuf> {
+    dirs::download_dir().or_else(|| get_data_dir().map(|dir| dir.join(EXPORTS_DIR_NAME)))
+}
+
+/// Writes `contents` to a new file named after `name` and returns its path.
+pub fn write_export(name: &str, extension: &str, contents: &str) -> Result<PathBuf, Errors> {
+    let dir = export_dir()
+        .ok_or_else(|| Errors::StorageError("Could not find a directory to export to".into()))?;
+
+    fs::create_dir_all(&dir)
+        .map_err(|e| Errors::StorageError(format!("Could not create {}: {}", dir.display(), e)))?;
+
+    let stem: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-");
+
+    let stem = if stem.is_empty() {
+        PROGRAM_NAME.to_string()
+    } else {
+        stem.chars().take(60).collect()
+    };
+
+    let file_name = format!(
+        "{}-{}.{}",
+        stem,
+        chrono::Local::now().format("%Y%m%dT%H%M%S"),
+        extension
+    );
+    let path = dir.join(file_name);
+
+    fs::write(&path, contents)
+        .map_err(|e| Errors::StorageError(format!("Could not write {}: {}", path.display(), e)))?;
+
+    Ok(path)
+}
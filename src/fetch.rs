@@ -0,0 +1,292 @@
+use headless_chrome::Tab;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::prelude::*;
+
+const DEFAULT_NAVIGATION_TIMEOUT_MS: u64 = 30_000;
+const DEFAULT_EVALUATION_TIMEOUT_MS: u64 = 10_000;
+const DEFAULT_WAIT_TIMEOUT_MS: u64 = 10_000;
+const DEFAULT_SCROLL_PAUSE_MS: u64 = 750;
+const MAX_SCROLLS_UNTIL_STABLE: u32 = 50;
+const NETWORK_IDLE_WINDOW: Duration = Duration::from_millis(500);
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How a page should be fetched. Every field is optional so that per-domain
+/// overrides only need to list what they change.
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct FetchSettings {
+    /// Time allowed for navigating to the page and for it to finish loading.
+    pub navigation_timeout_ms: Option<u64>,
+    /// Time allowed for each JavaScript evaluation, including page capture.
+    pub evaluation_timeout_ms: Option<u64>,
+    pub user_agent: Option<String>,
+    /// Extra HTTP headers sent with every request made by the page.
+    pub headers: HashMap<String, String>,
+    /// Proxy such as `http://host:port` or `socks5://host:port`, or `direct`
+    /// to bypass a proxy configured for all domains.
+    pub proxy: Option<String>,
+    /// CSS selector that must be present before the page is captured.
+    pub wait_for_selector: Option<String>,
+    /// Wait until no new network requests have been made for a short window.
+    pub wait_for_network_idle: Option<bool>,
+    pub wait_timeout_ms: Option<u64>,
+    /// Fixed delay before capturing the page.
+    pub delay_ms: Option<u64>,
+    /// Number of times to scroll to the bottom of the page.
+    pub scroll_count: Option<u32>,
+    /// Stop scrolling once the page height no longer grows.
+    pub scroll_until_stable: Option<bool>,
+    pub scroll_pause_ms: Option<u64>,
+}
+
+impl FetchSettings {
+    pub fn merge(&self, overrides: &FetchSettings) -> FetchSettings {
+        let mut headers = self.headers.clone();
+        headers.extend(overrides.headers.clone());
+
+        FetchSettings {
+            navigation_timeout_ms: overrides
+                .navigation_timeout_ms
+                .or(self.navigation_timeout_ms),
+            evaluation_timeout_ms: overrides
+                .evaluation_timeout_ms
+                .or(self.evaluation_timeout_ms),
+            user_agent: overrides
+                .user_agent
+                .clone()
+                .or_else(|| self.user_agent.clone()),
+            headers,
+            proxy: overrides.proxy.clone().or_else(|| self.proxy.clone()),
+            wait_for_selector: overrides
+                .wait_for_selector
+                .clone()
+                .or_else(|| self.wait_for_selector.clone()),
+            wait_for_network_idle: overrides
+                .wait_for_network_idle
+                .or(self.wait_for_network_idle),
+            wait_timeout_ms: overrides.wait_timeout_ms.or(self.wait_timeout_ms),
+            delay_ms: overrides.delay_ms.or(self.delay_ms),
+            scroll_count: overrides.scroll_count.or(self.scroll_count),
+            scroll_until_stable: overrides.scroll_until_stable.or(self.scroll_until_stable),
+            scroll_pause_ms: overrides.scroll_pause_ms.or(self.scroll_pause_ms),
+        }
+    }
+
+    pub fn navigation_timeout(&self) -> Duration {
+        Duration::from_millis(
+            self.navigation_timeout_ms
+                .unwrap_or(DEFAULT_NAVIGATION_TIMEOUT_MS),
+        )
+    }
+
+    pub fn evaluation_timeout(&self) -> Duration {
+        Duration::from_millis(
+            self.evaluation_timeout_ms
+                .unwrap_or(DEFAULT_EVALUATION_TIMEOUT_MS),
+        )
+    }
+
+    pub fn request_profile(&self) -> RequestProfile {
+        RequestProfile {
+            user_agent: self.user_agent.clone(),
+            headers: self.headers.clone().into_iter().collect(),
+        }
+    }
+
+    fn wait_timeout(&self) -> Duration {
+        Duration::from_millis(self.wait_timeout_ms.unwrap_or(DEFAULT_WAIT_TIMEOUT_MS))
+    }
+}
+
+/// The user agent and headers a tab was set up with. A tab can only be
+/// reused as it is for requests with the same profile.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RequestProfile {
+    user_agent: Option<String>,
+    headers: BTreeMap<String, String>,
+}
+
+impl RequestProfile {
+    /// Whether the tab still uses the browser's own user agent and headers.
+    pub fn is_default(&self) -> bool {
+        self.user_agent.is_none() && self.headers.is_empty()
+    }
+}
+
+/// Applies the timeout, user agent and headers for a request.
+pub fn apply_request_settings(tab: &Tab, settings: &FetchSettings) -> Result<(), Errors> {
+    tab.set_default_timeout(settings.navigation_timeout());
+
+    if let Some(user_agent) = &settings.user_agent {
+        tab.set_user_agent(user_agent, None, None)
+            .map_err(|e| Errors::BrowserError(format!("Could not set user agent: {}", e)))?;
+    }
+
+    if !settings.headers.is_empty() {
+        let headers: HashMap<&str, &str> = settings
+            .headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+
+        tab.set_extra_http_headers(headers)
+            .map_err(|e| Errors::BrowserError(format!("Could not set headers: {}", e)))?;
+    }
+
+    Ok(())
+}
+
+/// Builds a proxy auto-config script routing each domain through its
+/// configured proxy, or `None` if no proxy is configured at all.
+pub fn proxy_auto_config(
+    fetch: &FetchSettings,
+    overrides: &HashMap<String, FetchSettings>,
+) -> Option<String> {
+    let mut domain_proxies: Vec<(&String, &str)> = overrides
+        .iter()
+        .filter_map(|(domain, settings)| settings.proxy.as_deref().map(|proxy| (domain, proxy)))
+        .collect();
+
+    if fetch.proxy.is_none() && domain_proxies.is_empty() {
+        return None;
+    }
+
+    domain_proxies.sort_by_key(|(domain, _)| std::cmp::Reverse(domain.len()));
+
+    let mut script = String::from("function FindProxyForURL(url, host) {\n");
+
+    for (domain, proxy) in domain_proxies {
+        script.push_str(&format!(
+            "  if (host == \"{0}\" || dnsDomainIs(host, \".{0}\")) return \"{1}\";\n",
+            domain,
+            to_pac_proxy(proxy)
+        ));
+    }
+
+    script.push_str(&format!(
+        "  return \"{}\";\n}}\n",
+        fetch
+            .proxy
+            .as_deref()
+            .map_or("DIRECT".to_string(), to_pac_proxy)
+    ));
+
+    Some(script)
+}
+
+fn to_pac_proxy(proxy: &str) -> String {
+    if proxy.eq_ignore_ascii_case("direct") {
+        return "DIRECT".to_string();
+    }
+
+    let (scheme, address) = proxy.split_once("://").unwrap_or(("http", proxy));
+    let address = address.trim_end_matches('/');
+
+    match scheme {
+        "socks5" | "socks5h" => format!("SOCKS5 {}", address),
+        "socks" | "socks4" => format!("SOCKS {}", address),
+        "https" => format!("HTTPS {}", address),
+        _ => format!("PROXY {}", address),
+    }
+}
+
+/// Runs the configured wait strategies against a tab that has finished
+/// navigating, so that late-rendered content is present when it is captured.
+pub fn wait_for_content(tab: &Tab, settings: &FetchSettings) -> Result<(), Errors> {
+    if let Some(selector) = &settings.wait_for_selector {
+        log::debug!("Waiting for selector: {}", selector);
+
+        if let Err(e) = tab.wait_for_element_with_custom_timeout(selector, settings.wait_timeout())
+        {
+            log::warn!("Selector {} did not appear: {}", selector, e);
+        }
+    }
+
+    if settings.wait_for_network_idle.unwrap_or(false) {
+        wait_for_network_idle(tab, settings.wait_timeout())?;
+    }
+
+    if let Some(delay_ms) = settings.delay_ms {
+        log::debug!("Waiting for {}ms", delay_ms);
+        thread::sleep(Duration::from_millis(delay_ms));
+    }
+
+    let until_stable = settings.scroll_until_stable.unwrap_or(false);
+    let scroll_count = match settings.scroll_count {
+        Some(count) => count,
+        None if until_stable => MAX_SCROLLS_UNTIL_STABLE,
+        None => 0,
+    };
+
+    if scroll_count > 0 {
+        let pause =
+            Duration::from_millis(settings.scroll_pause_ms.unwrap_or(DEFAULT_SCROLL_PAUSE_MS));
+        scroll(tab, scroll_count, until_stable, pause)?;
+    }
+
+    Ok(())
+}
+
+fn wait_for_network_idle(tab: &Tab, timeout: Duration) -> Result<(), Errors> {
+    log::debug!("Waiting for network idle");
+
+    let start = Instant::now();
+    let mut last_count = evaluate_number(tab, "performance.getEntriesByType('resource').length")?;
+    let mut last_change = Instant::now();
+
+    while start.elapsed() < timeout {
+        thread::sleep(POLL_INTERVAL);
+
+        let count = evaluate_number(tab, "performance.getEntriesByType('resource').length")?;
+
+        if count != last_count {
+            last_count = count;
+            last_change = Instant::now();
+        } else if last_change.elapsed() >= NETWORK_IDLE_WINDOW {
+            return Ok(());
+        }
+    }
+
+    log::warn!("Network did not become idle within {:?}", timeout);
+
+    Ok(())
+}
+
+fn scroll(tab: &Tab, count: u32, until_stable: bool, pause: Duration) -> Result<(), Errors> {
+    let mut last_height = evaluate_number(tab, "document.documentElement.scrollHeight")?;
+
+    for index in 0..count {
+        log::debug!("Scrolling page ({}/{})", index + 1, count);
+
+        tab.evaluate(
+            "window.scrollTo(0, document.documentElement.scrollHeight)",
+            false,
+        )
+        .map_err(|e| Errors::BrowserError(format!("Could not scroll page: {}", e)))?;
+
+        thread::sleep(pause);
+
+        let height = evaluate_number(tab, "document.documentElement.scrollHeight")?;
+
+        if until_stable && height <= last_height {
+            log::debug!("Page height stopped growing after {} scrolls", index + 1);
+            break;
+        }
+
+        last_height = height;
+    }
+
+    Ok(())
+}
+
+fn evaluate_number(tab: &Tab, expression: &str) -> Result<f64, Errors> {
+    tab.evaluate(expression, false)
+        .map_err(|e| Errors::BrowserError(format!("Could not evaluate JavaScript: {}", e)))?
+        .value
+        .and_then(|value| value.as_f64())
+        .ok_or_else(|| Errors::BrowserError("Expression did not return a number".into()))
+}
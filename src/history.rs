@@ -0,0 +1,36 @@
+use crate::constants::MAX_HISTORY_ENTRIES;
+use crate::storage;
+
+const HISTORY_FILE_NAME: &str = "history.json";
+
+/// Previously visited URLs, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    entries: Vec<String>,
+}
+
+impl History {
+    pub fn load() -> Self {
+        History {
+            entries: storage::load(HISTORY_FILE_NAME),
+        }
+    }
+
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    pub fn record(&mut self, url: &str) {
+        self.entries.retain(|entry| entry != url);
+        self.entries.push(url.to_string());
+
+        if self.entries.len() > MAX_HISTORY_ENTRIES {
+            let excess = self.entries.len() - MAX_HISTORY_ENTRIES;
+            self.entries.drain(..excess);
+        }
+
+        if let Err(e) = storage::save(HISTORY_FILE_NAME, &self.entries) {
+            log::warn!("Could not save history: {:?}", e);
+        }
+    }
+}
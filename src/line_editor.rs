@@ -0,0 +1,431 @@
+/// Single-line text editor backing the navigation input.
+#[derive(Clone, Debug, Default)]
+pub struct LineEditor {
+    buffer: Vec<char>,
+    cursor: usize,
+    history_index: Option<usize>,
+    draft: Option<String>,
+    completion: Option<Completion>,
+}
+
+#[derive(Clone, Debug)]
+struct Completion {
+    matches: Vec<String>,
+    index: usize,
+}
+
+impl LineEditor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text(&self) -> String {
+        self.buffer.iter().collect()
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.reset_recall();
+        self.replace(text);
+    }
+
+    pub fn insert_char(&mut self, ch: char) {
+        self.reset_recall();
+        self.buffer.insert(self.cursor, ch);
+        self.cursor += 1;
+    }
+
+    pub fn insert_str(&mut self, text: &str) {
+        for ch in text.chars().filter(|ch| !ch.is_control()) {
+            self.insert_char(ch);
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.reset_recall();
+            self.cursor -= 1;
+            self.buffer.remove(self.cursor);
+        }
+    }
+
+    pub fn delete(&mut self) {
+        if self.cursor < self.buffer.len() {
+            self.reset_recall();
+            self.buffer.remove(self.cursor);
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.buffer.len());
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = self.buffer.len();
+    }
+
+    pub fn move_word_left(&mut self) {
+        self.cursor = self.previous_word_boundary();
+    }
+
+    pub fn move_word_right(&mut self) {
+        self.cursor = self.next_word_boundary();
+    }
+
+    pub fn delete_word_before(&mut self) {
+        let start = self.previous_word_boundary();
+        if start < self.cursor {
+            self.reset_recall();
+            self.buffer.drain(start..self.cursor);
+            self.cursor = start;
+        }
+    }
+
+    pub fn delete_word_after(&mut self) {
+        let end = self.next_word_boundary();
+        if end > self.cursor {
+            self.reset_recall();
+            self.buffer.drain(self.cursor..end);
+        }
+    }
+
+    pub fn delete_to_start(&mut self) {
+        if self.cursor > 0 {
+            self.reset_recall();
+            self.buffer.drain(..self.cursor);
+            self.cursor = 0;
+        }
+    }
+
+    pub fn delete_to_end(&mut self) {
+        if self.cursor < self.buffer.len() {
+            self.reset_recall();
+            self.buffer.truncate(self.cursor);
+        }
+    }
+
+    /// Steps back through `history`, which is ordered oldest first.
+    pub fn history_previous(&mut self, history: &[String]) {
+        if history.is_empty() {
+            return;
+        }
+
+        let index = match self.history_index {
+            None => {
+                self.draft = Some(self.text());
+                history.len() - 1
+            }
+            Some(index) => index.saturating_sub(1),
+        };
+
+        self.history_index = Some(index);
+        self.completion = None;
+        self.replace(&history[index]);
+    }
+
+    pub fn history_next(&mut self, history: &[String]) {
+        let Some(index) = self.history_index else {
+            return;
+        };
+
+        self.completion = None;
+
+        if index + 1 < history.len() {
+            self.history_index = Some(index + 1);
+            self.replace(&history[index + 1]);
+        } else {
+            self.history_index = None;
+            let draft = self.draft.take().unwrap_or_default();
+            self.replace(&draft);
+        }
+    }
+
+    /// Replaces the input with the best fuzzy match among `candidates`;
+    /// repeated calls cycle through the remaining matches.
+    pub fn complete(&mut self, candidates: &[String]) {
+        if let Some(completion) = &mut self.completion {
+            completion.index = (completion.index + 1) % completion.matches.len();
+            let next = completion.matches[completion.index].clone();
+            self.replace(&next);
+            return;
+        }
+
+        let query = self.text();
+
+        let mut scored: Vec<(i64, &String)> = candidates
+            .iter()
+            .filter_map(|candidate| fuzzy_score(&query, candidate).map(|score| (score, candidate)))
+            .collect();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+
+        let mut matches: Vec<String> = Vec::new();
+        for (_, candidate) in scored {
+            if !matches.contains(candidate) {
+                matches.push(candidate.clone());
+            }
+        }
+
+        if let Some(first) = matches.first().cloned() {
+            self.history_index = None;
+            self.completion = Some(Completion { matches, index: 0 });
+            self.replace(&first);
+        }
+    }
+
+    fn replace(&mut self, text: &str) {
+        self.buffer = text.chars().collect();
+        self.cursor = self.buffer.len();
+    }
+
+    fn reset_recall(&mut self) {
+        self.history_index = None;
+        self.draft = None;
+        self.completion = None;
+    }
+
+    fn previous_word_boundary(&self) -> usize {
+        let mut position = self.cursor;
+        while position > 0 && !is_word_char(self.buffer[position - 1]) {
+            position -= 1;
+        }
+        while position > 0 && is_word_char(self.buffer[position - 1]) {
+            position -= 1;
+        }
+        position
+    }
+
+    fn next_word_boundary(&self) -> usize {
+        let mut position = self.cursor;
+        while position < self.buffer.len() && !is_word_char(self.buffer[position]) {
+            position += 1;
+        }
+        while position < self.buffer.len() && is_word_char(self.buffer[position]) {
+            position += 1;
+        }
+        position
+    }
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric()
+}
+
+/// Scores `candidate` if every character of `query` appears in it in order,
+/// favouring consecutive runs and matches near the start.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score: i64 = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for query_char in query.to_lowercase().chars() {
+        let offset = candidate[position..]
+            .iter()
+            .position(|&ch| ch == query_char)?;
+        let index = position + offset;
+
+        score += match previous_match {
+            Some(previous) if previous + 1 == index => 5,
+            _ => 1,
+        };
+
+        previous_match = Some(index);
+        position = index + 1;
+    }
+
+    score -= previous_match.map_or(0, |_| candidate.len() as i64 / 10);
+
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor_with(text: &str) -> LineEditor {
+        let mut editor = LineEditor::new();
+        editor.set_text(text);
+        editor
+    }
+
+    fn history(entries: &[&str]) -> Vec<String> {
+        entries.iter().map(|entry| entry.to_string()).collect()
+    }
+
+    #[test]
+    fn edits_multi_byte_text_by_character() {
+        let mut editor = editor_with("héllo wörld");
+        assert_eq!(editor.cursor(), 11);
+
+        editor.move_left();
+        editor.move_left();
+        editor.backspace();
+        assert_eq!(editor.text(), "héllo wöld");
+        assert_eq!(editor.cursor(), 8);
+
+        editor.insert_char('r');
+        editor.delete();
+        assert_eq!(editor.text(), "héllo wörd");
+        assert_eq!(editor.cursor(), 9);
+    }
+
+    #[test]
+    fn cursor_stays_within_bounds() {
+        let mut editor = editor_with("日本");
+
+        editor.move_right();
+        assert_eq!(editor.cursor(), 2);
+
+        editor.move_home();
+        editor.move_left();
+        assert_eq!(editor.cursor(), 0);
+
+        editor.backspace();
+        assert_eq!(editor.text(), "日本");
+
+        editor.move_end();
+        editor.delete();
+        assert_eq!(editor.text(), "日本");
+    }
+
+    #[test]
+    fn inserts_strings_without_control_characters() {
+        let mut editor = editor_with("ab");
+        editor.move_left();
+        editor.insert_str("ç\n\té");
+
+        assert_eq!(editor.text(), "açéb");
+        assert_eq!(editor.cursor(), 3);
+    }
+
+    #[test]
+    fn moves_by_words() {
+        let mut editor = editor_with("café.example/über uns");
+
+        editor.move_word_left();
+        assert_eq!(editor.cursor(), 18);
+
+        editor.move_word_left();
+        assert_eq!(editor.cursor(), 13);
+
+        editor.move_word_left();
+        editor.move_word_left();
+        assert_eq!(editor.cursor(), 0);
+
+        editor.move_word_left();
+        assert_eq!(editor.cursor(), 0);
+
+        editor.move_word_right();
+        assert_eq!(editor.cursor(), 4);
+
+        editor.move_end();
+        editor.move_word_right();
+        assert_eq!(editor.cursor(), 21);
+    }
+
+    #[test]
+    fn deletes_words_around_the_cursor() {
+        let mut editor = editor_with("grüße an alle");
+
+        editor.delete_word_before();
+        assert_eq!(editor.text(), "grüße an ");
+        assert_eq!(editor.cursor(), 9);
+
+        editor.move_home();
+        editor.delete_word_after();
+        assert_eq!(editor.text(), " an ");
+        assert_eq!(editor.cursor(), 0);
+
+        editor.delete_word_before();
+        assert_eq!(editor.text(), " an ");
+    }
+
+    #[test]
+    fn deletes_to_either_end() {
+        let mut editor = editor_with("ñandú azul");
+        editor.move_word_left();
+
+        editor.delete_to_end();
+        assert_eq!(editor.text(), "ñandú ");
+
+        editor.move_left();
+        editor.delete_to_start();
+        assert_eq!(editor.text(), " ");
+        assert_eq!(editor.cursor(), 0);
+    }
+
+    #[test]
+    fn recalls_history_and_restores_the_draft() {
+        let history = history(&["first.example", "zweite.example"]);
+        let mut editor = editor_with("entwürf");
+
+        editor.history_previous(&history);
+        assert_eq!(editor.text(), "zweite.example");
+
+        editor.history_previous(&history);
+        assert_eq!(editor.text(), "first.example");
+
+        editor.history_previous(&history);
+        assert_eq!(editor.text(), "first.example");
+
+        editor.history_next(&history);
+        assert_eq!(editor.text(), "zweite.example");
+
+        editor.history_next(&history);
+        assert_eq!(editor.text(), "entwürf");
+        assert_eq!(editor.cursor(), 7);
+
+        editor.history_next(&history);
+        assert_eq!(editor.text(), "entwürf");
+    }
+
+    #[test]
+    fn editing_ends_history_recall() {
+        let history = history(&["one", "two"]);
+        let mut editor = editor_with("é");
+
+        editor.history_previous(&history);
+        editor.insert_char('!');
+        editor.history_next(&history);
+        assert_eq!(editor.text(), "two!");
+
+        editor.history_previous(&history);
+        assert_eq!(editor.text(), "two");
+        editor.history_next(&history);
+        assert_eq!(editor.text(), "two!");
+    }
+
+    #[test]
+    fn ignores_empty_history() {
+        let mut editor = editor_with("ü");
+
+        editor.history_previous(&[]);
+        editor.history_next(&[]);
+
+        assert_eq!(editor.text(), "ü");
+    }
+
+    #[test]
+    fn cycles_through_completions() {
+        let candidates = history(&["https://münchen.de", "https://news.example", "mail.example"]);
+        let mut editor = editor_with("mü");
+
+        editor.complete(&candidates);
+        assert_eq!(editor.text(), "https://münchen.de");
+        assert_eq!(editor.cursor(), 18);
+
+        editor.complete(&candidates);
+        assert_eq!(editor.text(), "https://münchen.de");
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::prelude::*;
+use crate::storage;
+
+const LOAD_TIMES_FILE_NAME: &str = "load_times.json";
+
+/// Running total of page load durations for one domain.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+struct LoadTimes {
+    count: u64,
+    total_ms: u64,
+}
+
+pub fn record_load_time(url: &str, duration: Duration) {
+    let mut load_times: HashMap<String, LoadTimes> = storage::load(LOAD_TIMES_FILE_NAME);
+
+    let entry = load_times.entry(minimize_url(url)).or_default();
+    entry.count += 1;
+    entry.total_ms += duration.as_millis() as u64;
+
+    if let Err(e) = storage::save(LOAD_TIMES_FILE_NAME, &load_times) {
+        log::warn!("Could not save load times: {:?}", e);
+    }
+}
+
+pub fn average_load_time(url: &str) -> Option<Duration> {
+    let load_times: HashMap<String, LoadTimes> = storage::load(LOAD_TIMES_FILE_NAME);
+
+    load_times
+        .get(&minimize_url(url))
+        .filter(|entry| entry.count > 0)
+        .map(|entry| Duration::from_millis(entry.total_ms / entry.count))
+}
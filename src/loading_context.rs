@@ -1,10 +1,14 @@
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 #[derive(Clone, Debug)]
 pub struct LoadingContext {
+    pub started_at: Instant,
+    pub expected_duration: Option<Duration>,
     pub global_tokens: u64,
     pub stage_tokens: HashMap<String, u64>,
     pub stage_messages: Vec<(String, Vec<StageMessage>)>,
+    pub stage_timings: HashMap<String, StageTiming>,
 }
 
 #[derive(Clone, Debug)]
@@ -13,12 +17,40 @@ pub struct StageMessage {
     pub tokens: u64,
 }
 
+#[derive(Clone, Debug)]
+pub struct StageTiming {
+    pub started_at: Instant,
+    pub finished_at: Option<Instant>,
+}
+
 impl LoadingContext {
-    pub fn new() -> Self {
+    pub fn new(expected_duration: Option<Duration>) -> Self {
         Self {
+            started_at: Instant::now(),
+            expected_duration,
             global_tokens: 0,
             stage_tokens: HashMap::new(),
             stage_messages: Vec::new(),
+            stage_timings: HashMap::new(),
+        }
+    }
+}
+
+impl StageTiming {
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            finished_at: None,
         }
     }
+
+    pub fn is_active(&self) -> bool {
+        self.finished_at.is_none()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.finished_at
+            .unwrap_or_else(Instant::now)
+            .duration_since(self.started_at)
+    }
 }
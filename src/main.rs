@@ -1,8 +1,10 @@
 use clap::{App as ClapApp, Arg};
+use crossterm::event::{DisableBracketedPaste, EnableBracketedPaste};
 use fern::Dispatch;
 use headless_chrome::{Browser, LaunchOptions};
 use log::LevelFilter;
 use parversion::provider::sqlite::SqliteProvider;
+use std::ffi::OsStr;
 use std::fs;
 use std::fs::File;
 use std::path::PathBuf;
@@ -10,21 +12,37 @@ use std::sync::Arc;
 use std::time::Duration;
 
 mod app;
+mod browser;
+mod config;
 mod constants;
 mod content;
 mod context;
+mod cookies;
+mod export;
+mod fetch;
+mod history;
+mod line_editor;
+mod load_times;
 mod loading_context;
 mod prelude;
+mod snapshots;
+mod storage;
 mod types;
 mod ui;
+mod usage;
 mod utilities;
+mod watch;
 
 use crate::app::App;
+use crate::browser::TabPool;
+use crate::config::Config;
+use crate::constants::PROGRAM_NAME;
 use crate::context::Context;
+use crate::cookies::{read_all_cookies, save_session_cookies};
+use crate::fetch::proxy_auto_config;
 use crate::prelude::*;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
-const PROGRAM_NAME: &str = "pori";
 
 fn parse_arguments() -> clap::ArgMatches {
     ClapApp::new(PROGRAM_NAME)
@@ -35,6 +53,16 @@ fn parse_arguments() -> clap::ArgMatches {
                 .long("version")
                 .help("Display program version"),
         )
+        .subcommand(ClapApp::new("usage").about("Summarize translation token usage"))
+        .subcommand(
+            ClapApp::new("login")
+                .about("Open a browser window to sign in to a site and keep the session")
+                .arg(
+                    Arg::with_name("url")
+                        .required(true)
+                        .help("Page to sign in on"),
+                ),
+        )
         .get_matches()
 }
 
@@ -64,17 +92,79 @@ directory"
     ).expect("Could not initialize sqlite provider")))
 }
 
-async fn init_browser() -> Result<Browser, Errors> {
-    log::info!("Initializing web browser...");
+fn launch_browser(config: &Config, headless: bool) -> Result<Browser, Errors> {
+    log::info!("Launching web browser...");
+
+    let user_data_dir = config.browser.profile_dir();
+
+    if let Some(dir) = &user_data_dir {
+        log::debug!("user_data_dir: {}", dir.display());
+        fs::create_dir_all(dir)
+            .map_err(|e| Errors::BrowserError(format!("Could not create profile directory: {}", e)))?;
+    }
+
+    let proxy_argument = proxy_auto_config(&config.fetch, &config.fetch_overrides).map(|script| {
+        format!(
+            "--proxy-pac-url=data:application/x-ns-proxy-autoconfig,{}",
+            percent_encode(&script)
+        )
+    });
+
+    let args: Vec<&OsStr> = proxy_argument.iter().map(OsStr::new).collect();
 
     Browser::new(LaunchOptions {
-        headless: true,
+        headless,
         idle_browser_timeout: Duration::MAX,
+        user_data_dir,
+        args,
         ..Default::default()
     })
     .map_err(|e| Errors::BrowserError(format!("Could not start web browser: {}", e)))
 }
 
+async fn init_browser(config: Arc<Config>) -> Result<TabPool, Errors> {
+    log::info!("Initializing web browser...");
+
+    let settings = config.browser.clone();
+
+    TabPool::new(move || launch_browser(&config, true), &settings)
+}
+
+fn login(config: &Config, input: &str) -> Result<(), Errors> {
+    let url = expand_navigation_input(input, &config.search_keywords).ok_or(Errors::InvalidUrl)?;
+
+    if let Some(pid) = config.browser.profile_lock_holder() {
+        return Err(Errors::BrowserError(format!(
+            "The browser profile is in use by process {}. Quit {} before signing in, as the sign-in window cannot share the profile with it.",
+            pid, PROGRAM_NAME
+        )));
+    }
+
+    let browser = launch_browser(config, false)?;
+
+    let tab = browser
+        .new_tab()
+        .map_err(|e| Errors::BrowserError(format!("Could not create new tab: {}", e)))?;
+
+    tab.navigate_to(&url)
+        .map_err(|e| Errors::BrowserError(format!("Could not navigate: {}", e)))?;
+
+    println!("Sign in to {} in the browser window, then press Enter here to save the session.", url);
+
+    let mut line = String::new();
+    std::io::stdin()
+        .read_line(&mut line)
+        .map_err(|e| Errors::UnexpectedError(format!("Could not read input: {}", e)))?;
+
+    let cookies = read_all_cookies(&tab)?;
+
+    save_session_cookies(&cookies)?;
+
+    println!("Saved {} cookies", cookies.len());
+
+    Ok(())
+}
+
 fn init_logging() {
     let log_file = File::create("debug.log").expect("Could not create log file");
 
@@ -112,18 +202,34 @@ async fn run() -> Result<(), Errors> {
         return Ok(());
     }
 
+    let config = Arc::new(Config::load()?);
+
+    match matches.subcommand() {
+        Some(("login", login_matches)) => {
+            return login(&config, login_matches.value_of("url").unwrap_or_default());
+        }
+        Some(("usage", _)) => {
+            usage::print_summary();
+            return Ok(());
+        }
+        _ => {}
+    }
+
     let provider = init_provider().await?;
 
-    let browser = init_browser().await?;
+    let tabs = init_browser(Arc::clone(&config)).await?;
 
-    let context = Context::new(provider, browser);
+    let context = Context::new(provider, tabs, config);
 
     let mut terminal = ratatui::init();
+    let _ = crossterm::execute!(std::io::stdout(), EnableBracketedPaste);
+
     let mut app = App::new(context);
     let result = app.run(&mut terminal).await;
 
     log::debug!("result: {:?}", result);
 
+    let _ = crossterm::execute!(std::io::stdout(), DisableBracketedPaste);
     ratatui::restore();
 
     Ok(())
@@ -0,0 +1,87 @@
+use chrono::NaiveDateTime;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::content::digest::{Digest, deserialize_to_digest};
+use crate::prelude::*;
+
+const SNAPSHOTS_DIR_NAME: &str = "snapshots";
+const SNAPSHOT_FILE_FORMAT: &str = "%Y%m%dT%H%M%S";
+const SNAPSHOT_DISPLAY_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+const MAX_SNAPSHOTS_PER_PAGE: usize = 100;
+
+/// Translated content of a page as it was at one point in time.
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    pub taken_at: NaiveDateTime,
+    path: PathBuf,
+}
+
+impl Snapshot {
+    pub fn label(&self) -> String {
+        self.taken_at.format(SNAPSHOT_DISPLAY_FORMAT).to_string()
+    }
+
+    pub fn load_digest(&self) -> Result<Digest, Errors> {
+        let data = fs::read_to_string(&self.path)
+            .map_err(|e| Errors::StorageError(format!("Could not read snapshot: {}", e)))?;
+
+        deserialize_to_digest(&data)
+            .map_err(|e| Errors::StorageError(format!("Could not parse snapshot: {}", e)))
+    }
+}
+
+/// Stores translated data for `url`, unless it is identical to the most
+/// recent snapshot.
+pub fn save_snapshot(url: &str, data: &str) {
+    let Some(dir) = snapshot_dir(url) else {
+        return;
+    };
+
+    let snapshots = list_snapshots(url);
+
+    if let Some(latest) = snapshots.first()
+        && fs::read_to_string(&latest.path).is_ok_and(|latest_data| latest_data == data)
+    {
+        return;
+    }
+
+    let file_name = format!("{}.json", chrono::Local::now().format(SNAPSHOT_FILE_FORMAT));
+
+    let result = fs::create_dir_all(&dir).and_then(|_| fs::write(dir.join(file_name), data));
+
+    if let Err(e) = result {
+        log::warn!("Could not save snapshot: {}", e);
+        return;
+    }
+
+    for snapshot in snapshots.iter().skip(MAX_SNAPSHOTS_PER_PAGE - 1) {
+        let _ = fs::remove_file(&snapshot.path);
+    }
+}
+
+/// Snapshots of `url`, newest first.
+pub fn list_snapshots(url: &str) -> Vec<Snapshot> {
+    let Some(entries) = snapshot_dir(url).and_then(|dir| fs::read_dir(dir).ok()) else {
+        return Vec::new();
+    };
+
+    let mut snapshots: Vec<Snapshot> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            let stem = path.file_stem()?.to_str()?;
+            let taken_at = NaiveDateTime::parse_from_str(stem, SNAPSHOT_FILE_FORMAT).ok()?;
+
+            Some(Snapshot { taken_at, path })
+        })
+        .collect();
+
+    snapshots.sort_by_key(|snapshot| std::cmp::Reverse(snapshot.taken_at));
+
+    snapshots
+}
+
+fn snapshot_dir(url: &str) -> Option<PathBuf> {
+    get_data_dir().map(|dir| dir.join(SNAPSHOTS_DIR_NAME).join(to_safe_dir_name(url)))
+}
@@ -0,0 +1,42 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs;
+
+use crate::prelude::*;
+
+pub fn load<T: DeserializeOwned + Default>(file_name: &str) -> T {
+    let Some(path) = get_data_dir().map(|dir| dir.join(file_name)) else {
+        log::warn!("Could not find data directory, not loading {}", file_name);
+        return T::default();
+    };
+
+    if !path.exists() {
+        return T::default();
+    }
+
+    match fs::read_to_string(&path).map(|text| serde_json::from_str(&text)) {
+        Ok(Ok(value)) => value,
+        Ok(Err(e)) => {
+            log::warn!("Could not parse {}: {}", path.display(), e);
+            T::default()
+        }
+        Err(e) => {
+            log::warn!("Could not read {}: {}", path.display(), e);
+            T::default()
+        }
+    }
+}
+
+pub fn save<T: Serialize>(file_name: &str, value: &T) -> Result<(), Errors> {
+    let data_dir = get_data_dir()
+        .ok_or_else(|| Errors::StorageError("Could not find data directory".into()))?;
+
+    fs::create_dir_all(&data_dir)
+        .map_err(|e| Errors::StorageError(format!("Could not create data directory: {}", e)))?;
+
+    let text = serde_json::to_string_pretty(value)
+        .map_err(|e| Errors::StorageError(format!("Could not serialize {}: {}", file_name, e)))?;
+
+    fs::write(data_dir.join(file_name), text)
+        .map_err(|e| Errors::StorageError(format!("Could not write {}: {}", file_name, e)))
+}
@@ -1,3 +1,5 @@
+use crate::content::ContentType;
+
 #[derive(Clone, Debug)]
 pub enum Errors {
     UnexpectedError(String),
@@ -6,7 +8,12 @@ pub enum Errors {
     TranslationError(String),
     NormalizationError(String),
     UnexpectedContentType(String),
+    ConfigError(String),
+    StorageError(String),
+    Timeout(String),
+    ValidationError(Vec<String>),
     InvalidUrl,
+    BudgetExceeded(u64),
 }
 
 #[derive(Clone, Debug)]
@@ -30,4 +37,10 @@ impl Mode {
 pub enum Action {
     //Open(String),
     OpenUsingRenderingEngine(String),
+    PlayMedia(String),
+    Navigate(String),
+    NavigateAs(String, ContentType),
+    LoadNextPage(String),
+    ShowSnapshots,
+    ShowNotice(String),
 }
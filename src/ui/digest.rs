@@ -4,15 +4,20 @@ use ratatui::{
     layout::Rect,
     style::{
         Style,
-        palette::tailwind::{BLUE, GRAY, GREEN},
+        palette::tailwind::{BLUE, GRAY, GREEN, YELLOW},
     },
     text::{Line, Span, Text},
     widgets::{List, ListItem, ListState, StatefulWidget},
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::content::digest::Digest;
+use crate::content::ContentType;
+use crate::content::digest::{ContentItem, Digest};
+use crate::content::paginated::Paginated;
 use crate::prelude::*;
+use crate::snapshots::Snapshot;
+use crate::ui::digest_diff::DiffView;
+use crate::ui::pager::Pager;
 
 struct EntryList {
     state: ListState,
@@ -24,6 +29,13 @@ pub struct DigestApp {
     column_ratios: HashMap<String, u32>,
     column_count: usize,
     selected_column_index: usize,
+    pager: Pager,
+    new_entries: HashSet<String>,
+    /// The first page as last fetched. Snapshots are taken of the first
+    /// page only, so diffs compare against it rather than against entries
+    /// appended from later pages or kept from earlier refreshes.
+    first_page: Option<Digest>,
+    diff_view: Option<DiffView>,
 }
 
 impl DigestApp {
@@ -36,10 +48,74 @@ impl DigestApp {
             column_ratios: HashMap::new(),
             column_count: 0,
             selected_column_index: 0,
+            pager: Pager::new(),
+            new_entries: HashSet::new(),
+            first_page: None,
+            diff_view: None,
         }
     }
 
     pub fn run(&mut self, digest: Digest) {
+        self.pager.reset();
+        self.new_entries.clear();
+        self.diff_view = None;
+        self.update_columns(&digest);
+        self.first_page = Some(digest.clone());
+        self.digest = Some(digest);
+    }
+
+    pub fn append(&mut self, page: Digest) {
+        self.pager.reset();
+
+        if let Some(mut digest) = self.digest.take() {
+            digest.append_page(page);
+            self.update_columns(&digest);
+            self.digest = Some(digest);
+        }
+    }
+
+    pub fn merge(&mut self, latest: Digest) -> usize {
+        let Some(mut digest) = self.digest.take() else {
+            self.run(latest);
+            return 0;
+        };
+
+        let selected = self
+            .entry_list
+            .state
+            .selected()
+            .and_then(|index| digest.entries.get(index))
+            .map(ContentItem::identity);
+
+        let first_page = self
+            .first_page
+            .replace(latest.clone())
+            .unwrap_or_else(Digest::new);
+        self.new_entries = digest.merge_latest(latest, &first_page);
+        self.update_columns(&digest);
+
+        if let Some(selected) = selected {
+            let index = digest
+                .entries
+                .iter()
+                .position(|entry| entry.identity() == selected);
+            self.entry_list.state.select(index.or(Some(0)));
+        }
+
+        self.digest = Some(digest);
+
+        self.new_entries.len()
+    }
+
+    pub fn show_snapshots(&mut self, snapshots: Vec<Snapshot>) {
+        self.diff_view = Some(DiffView::new(snapshots));
+    }
+
+    pub fn next_page_failed(&mut self) {
+        self.pager.reset();
+    }
+
+    fn update_columns(&mut self, digest: &Digest) {
         let column_count = digest.entries.iter().fold(0, |acc, entry| {
             let field_presence: Vec<bool> = vec![
                 entry.content.is_some(),
@@ -126,10 +202,14 @@ impl DigestApp {
 
         self.column_ratios = column_ratios;
         self.column_count = column_count;
-        self.digest = Some(digest);
     }
 
     pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        if let Some(diff_view) = &mut self.diff_view {
+            diff_view.render(area, buf);
+            return;
+        }
+
         let Some(digest) = &self.digest else {
             return;
         };
@@ -147,7 +227,7 @@ impl DigestApp {
             })
             .collect();
 
-        let items: Vec<ListItem> = digest
+        let mut items: Vec<ListItem> = digest
             .entries
             .iter()
             .enumerate()
@@ -159,7 +239,14 @@ impl DigestApp {
                     .clone()
                     .unwrap_or_else(|| "Untitled".to_string());
 
-                let title_line = Line::styled(title, Style::default().fg(GRAY.c300).bold());
+                let title_line = if self.new_entries.contains(&entry.identity()) {
+                    Line::from(vec![
+                        Span::styled("● ", Style::default().fg(YELLOW.c400)),
+                        Span::styled(title, Style::default().fg(YELLOW.c200).bold()),
+                    ])
+                } else {
+                    Line::styled(title, Style::default().fg(GRAY.c300).bold())
+                };
 
                 let mut spans = Vec::new();
 
@@ -230,6 +317,13 @@ impl DigestApp {
             })
             .collect();
 
+        if let Some(status) = self.pager.status(digest.next_page_url.as_deref()) {
+            items.push(ListItem::new(Line::styled(
+                status,
+                Style::default().fg(GRAY.c500),
+            )));
+        }
+
         let list = List::new(items)
             .highlight_symbol(">>")
             .repeat_highlight_symbol(false);
@@ -238,12 +332,22 @@ impl DigestApp {
     }
 
     pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        if let Some(diff_view) = &mut self.diff_view {
+            if let Some(first_page) = &self.first_page
+                && !diff_view.handle_key_event(key_event, first_page)
+            {
+                self.diff_view = None;
+            }
+
+            return None;
+        }
+
         match key_event.code {
             KeyCode::Char('h') => {
                 self.select_previous_column();
             }
             KeyCode::Char('j') => {
-                self.select_next();
+                return self.select_next();
             }
             KeyCode::Char('k') => {
                 self.select_previous();
@@ -251,6 +355,15 @@ impl DigestApp {
             KeyCode::Char('l') => {
                 self.select_next_column();
             }
+            KeyCode::Char('n') => {
+                return self.load_next_page();
+            }
+            KeyCode::Char('d') => {
+                return Some(Action::ShowSnapshots);
+            }
+            KeyCode::Char('a') => {
+                return self.open_author_profile();
+            }
             KeyCode::Enter => {
                 return self.select_row_column();
             }
@@ -303,12 +416,30 @@ impl DigestApp {
         None
     }
 
+    fn open_author_profile(&self) -> Option<Action> {
+        let row_index = self.entry_list.state.selected()?;
+        let entry = self.digest.as_ref()?.entries.get(row_index)?;
+        let url = entry.author.as_ref()?.url.clone()?;
+
+        Some(Action::NavigateAs(url, ContentType::Profile))
+    }
+
     fn select_previous(&mut self) {
         self.entry_list.state.select_previous();
     }
 
-    fn select_next(&mut self) {
-        self.entry_list.state.select_next();
+    fn select_next(&mut self) -> Option<Action> {
+        let digest = self.digest.as_ref()?;
+        self.pager.select_next(
+            &mut self.entry_list.state,
+            digest.entries.len(),
+            digest.next_page_url.as_deref(),
+        )
+    }
+
+    fn load_next_page(&mut self) -> Option<Action> {
+        let next_page_url = self.digest.as_ref()?.next_page_url.as_deref();
+        self.pager.load_next_page(next_page_url)
     }
 
     fn select_previous_column(&mut self) {
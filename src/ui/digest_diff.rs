@@ -0,0 +1,161 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{
+        Style,
+        palette::tailwind::{GRAY, GREEN, RED, YELLOW},
+    },
+    text::{Line, Span},
+    widgets::{List, ListItem, ListState, StatefulWidget},
+};
+
+use crate::content::digest::{Digest, DigestDiff};
+use crate::snapshots::Snapshot;
+
+/// Snapshot picker and the comparison against the chosen snapshot.
+pub enum DiffView {
+    Snapshots {
+        snapshots: Vec<Snapshot>,
+        state: ListState,
+    },
+    Diff {
+        since: String,
+        diff: DigestDiff,
+        state: ListState,
+    },
+}
+
+impl DiffView {
+    pub fn new(snapshots: Vec<Snapshot>) -> Self {
+        // The newest snapshot is usually the page being viewed.
+        let initial = if snapshots.len() > 1 { 1 } else { 0 };
+
+        DiffView::Snapshots {
+            snapshots,
+            state: ListState::default().with_selected(Some(initial)),
+        }
+    }
+
+    /// Returns `false` once the view should be closed.
+    pub fn handle_key_event(&mut self, key_event: KeyEvent, current: &Digest) -> bool {
+        match key_event.code {
+            KeyCode::Char('d') | KeyCode::Backspace => return false,
+            KeyCode::Char('j') => self.state_mut().select_next(),
+            KeyCode::Char('k') => self.state_mut().select_previous(),
+            KeyCode::Enter => self.compare(current),
+            _ => {}
+        }
+
+        true
+    }
+
+    fn state_mut(&mut self) -> &mut ListState {
+        match self {
+            DiffView::Snapshots { state, .. } | DiffView::Diff { state, .. } => state,
+        }
+    }
+
+    fn compare(&mut self, current: &Digest) {
+        let DiffView::Snapshots { snapshots, state } = self else {
+            return;
+        };
+
+        let Some(snapshot) = state.selected().and_then(|index| snapshots.get(index)) else {
+            return;
+        };
+
+        match snapshot.load_digest() {
+            Ok(earlier) => {
+                *self = DiffView::Diff {
+                    since: snapshot.label(),
+                    diff: current.diff(&earlier),
+                    state: ListState::default(),
+                };
+            }
+            Err(e) => log::error!("Could not load snapshot: {:?}", e),
+        }
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        match self {
+            DiffView::Snapshots { snapshots, state } => {
+                let mut items = vec![ListItem::new(Line::styled(
+                    "Compare with snapshot (Enter to compare, d to go back)",
+                    Style::default().fg(GRAY.c500),
+                ))];
+
+                items.extend(
+                    snapshots
+                        .iter()
+                        .map(|snapshot| ListItem::new(Line::raw(snapshot.label()))),
+                );
+
+                // Offset the selection past the heading row.
+                let mut offset_state = *state;
+                offset_state.select(state.selected().map(|index| index + 1));
+
+                let list = List::new(items).highlight_symbol(">>");
+                StatefulWidget::render(list, area, buf, &mut offset_state);
+            }
+            DiffView::Diff { since, diff, state } => {
+                let mut items = vec![ListItem::new(Line::styled(
+                    format!(
+                        "Changes since {}: {} added, {} removed, {} changed (d to go back)",
+                        since,
+                        diff.added.len(),
+                        diff.removed.len(),
+                        diff.changed.len()
+                    ),
+                    Style::default().fg(GRAY.c500),
+                ))];
+
+                for entry in &diff.added {
+                    items.push(ListItem::new(Line::styled(
+                        format!("+ {}", entry.title.as_deref().unwrap_or("Untitled")),
+                        Style::default().fg(GREEN.c500),
+                    )));
+                }
+
+                for entry in &diff.removed {
+                    items.push(ListItem::new(Line::styled(
+                        format!("- {}", entry.title.as_deref().unwrap_or("Untitled")),
+                        Style::default().fg(RED.c400),
+                    )));
+                }
+
+                for change in &diff.changed {
+                    let mut spans = vec![Span::styled(
+                        format!("~ {}", change.entry.title.as_deref().unwrap_or("Untitled")),
+                        Style::default().fg(YELLOW.c400),
+                    )];
+
+                    if change.previous_rank != change.rank {
+                        spans.push(Span::styled(
+                            format!("  rank {} → {}", change.previous_rank, change.rank),
+                            Style::default().fg(GRAY.c400),
+                        ));
+                    }
+
+                    if change.previous_score != change.entry.score {
+                        spans.push(Span::styled(
+                            format!(
+                                "  score {} → {}",
+                                change.previous_score.as_deref().unwrap_or("-"),
+                                change.entry.score.as_deref().unwrap_or("-")
+                            ),
+                            Style::default().fg(GRAY.c400),
+                        ));
+                    }
+
+                    items.push(ListItem::new(Line::from(spans)));
+                }
+
+                let list = List::new(items)
+                    .highlight_symbol(">>")
+                    .highlight_style(Style::default().bold());
+                StatefulWidget::render(list, area, buf, state);
+            }
+        }
+    }
+}
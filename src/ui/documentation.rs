@@ -0,0 +1,483 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{AMBER, BLUE, GRAY, GREEN},
+    },
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, ListState, Paragraph, StatefulWidget, Widget},
+};
+use std::collections::HashSet;
+
+use crate::content::ContentType;
+use crate::content::documentation::{BlockKind, Documentation, Link, Section};
+use crate::prelude::*;
+use crate::ui::text::wrap_text;
+
+#[derive(PartialEq)]
+enum Focus {
+    Contents,
+    Body,
+}
+
+pub struct DocumentationApp {
+    documentation: Option<Documentation>,
+    focus: Focus,
+    toc_state: ListState,
+    collapsed: HashSet<usize>,
+    scroll: usize,
+    /// Line of the body at which each section starts, as of the last render.
+    section_offsets: Vec<usize>,
+    body_height: usize,
+    /// Section and index of the link selected with `[` and `]`.
+    selected_link: Option<(usize, usize)>,
+}
+
+impl DocumentationApp {
+    pub fn new() -> Self {
+        Self {
+            documentation: None,
+            focus: Focus::Contents,
+            toc_state: ListState::default(),
+            collapsed: HashSet::new(),
+            scroll: 0,
+            section_offsets: Vec::new(),
+            body_height: 0,
+            selected_link: None,
+        }
+    }
+
+    pub fn run(&mut self, documentation: Documentation) {
+        self.focus = Focus::Contents;
+        self.toc_state.select(Some(0));
+        self.collapsed.clear();
+        self.scroll = 0;
+        self.section_offsets.clear();
+        self.selected_link = None;
+        self.documentation = Some(documentation);
+    }
+
+    /// Sections shown in the table of contents, leaving out those below a
+    /// collapsed heading.
+    fn visible_sections(&self) -> Vec<usize> {
+        let Some(documentation) = &self.documentation else {
+            return Vec::new();
+        };
+
+        let mut visible = Vec::new();
+        let mut collapsed_level: Option<u8> = None;
+
+        for (index, section) in documentation.sections.iter().enumerate() {
+            if let Some(level) = collapsed_level {
+                if section.level() > level {
+                    continue;
+                }
+                collapsed_level = None;
+            }
+
+            visible.push(index);
+
+            if self.collapsed.contains(&index) {
+                collapsed_level = Some(section.level());
+            }
+        }
+
+        visible
+    }
+
+    fn has_subsections(sections: &[Section], index: usize) -> bool {
+        sections
+            .get(index + 1)
+            .is_some_and(|next| next.level() > sections[index].level())
+    }
+
+    /// Section the body is currently scrolled to.
+    fn current_section(&self) -> Option<usize> {
+        self.section_offsets
+            .iter()
+            .rposition(|offset| *offset <= self.scroll)
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        if self.documentation.is_none() {
+            return;
+        }
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(30), Constraint::Percentage(70)])
+            .split(area);
+
+        let body_area = columns[1];
+        let (lines, section_offsets) = self.body_lines(body_area.width.saturating_sub(2) as usize);
+        self.section_offsets = section_offsets;
+        self.body_height = body_area.height.saturating_sub(2) as usize;
+        self.scroll = self.scroll.min(lines.len().saturating_sub(1));
+
+        let visible = self.visible_sections();
+        let current_section = self.current_section();
+        let Some(documentation) = &self.documentation else {
+            return;
+        };
+
+        let toc_items: Vec<ListItem> = visible
+            .iter()
+            .map(|index| {
+                let section = &documentation.sections[*index];
+
+                let marker = if !Self::has_subsections(&documentation.sections, *index) {
+                    "  "
+                } else if self.collapsed.contains(index) {
+                    "▸ "
+                } else {
+                    "▾ "
+                };
+
+                let style = if current_section == Some(*index) {
+                    Style::default().fg(GRAY.c200).bold()
+                } else {
+                    Style::default().fg(GRAY.c400)
+                };
+
+                ListItem::new(Line::styled(
+                    format!(
+                        "{}{}{}",
+                        "  ".repeat(section.level() as usize - 1),
+                        marker,
+                        section.heading.as_deref().unwrap_or("Untitled")
+                    ),
+                    style,
+                ))
+            })
+            .collect();
+
+        let toc = List::new(toc_items)
+            .block(focus_block("Contents", self.focus == Focus::Contents))
+            .highlight_symbol(">>")
+            .highlight_style(Style::default().fg(BLUE.c400));
+
+        StatefulWidget::render(toc, columns[0], buf, &mut self.toc_state);
+
+        let title = documentation.title.clone().unwrap_or_default();
+
+        Paragraph::new(lines)
+            .scroll((self.scroll.min(u16::MAX as usize) as u16, 0))
+            .block(focus_block(&title, self.focus == Focus::Body))
+            .render(body_area, buf);
+    }
+
+    /// Lays out the body at `width`, returning its lines and the line at
+    /// which each section starts.
+    fn body_lines(&self, width: usize) -> (Vec<Line<'static>>, Vec<usize>) {
+        let mut lines = Vec::new();
+        let mut offsets = Vec::new();
+
+        let Some(documentation) = &self.documentation else {
+            return (lines, offsets);
+        };
+
+        for (section_index, section) in documentation.sections.iter().enumerate() {
+            offsets.push(lines.len());
+
+            let heading_style = match section.level() {
+                1 => Style::default().fg(GREEN.c300).bold().underlined(),
+                2 => Style::default().fg(GREEN.c400).bold(),
+                _ => Style::default().fg(GREEN.c500).bold(),
+            };
+
+            lines.push(Line::styled(
+                section.heading.clone().unwrap_or_default(),
+                heading_style,
+            ));
+            lines.push(Line::default());
+
+            for block in &section.blocks {
+                match block.kind() {
+                    BlockKind::Code => {
+                        lines.push(Line::styled(
+                            format!("── {} ", block.language.as_deref().unwrap_or("code")),
+                            Style::default().fg(GRAY.c600),
+                        ));
+
+                        for line in block.text.as_deref().unwrap_or_default().lines() {
+                            lines.push(Line::from(vec![
+                                Span::styled("│ ", Style::default().fg(GRAY.c600)),
+                                Span::styled(line.to_string(), Style::default().fg(AMBER.c300)),
+                            ]));
+                        }
+                    }
+                    BlockKind::List => {
+                        let items = if block.items.is_empty() {
+                            block
+                                .text
+                                .as_deref()
+                                .unwrap_or_default()
+                                .lines()
+                                .map(str::to_string)
+                                .collect()
+                        } else {
+                            block.items.clone()
+                        };
+
+                        for item in items {
+                            for (index, line) in wrap_text(&item, width.saturating_sub(2))
+                                .into_iter()
+                                .enumerate()
+                            {
+                                let bullet = if index == 0 { "• " } else { "  " };
+                                let mut spans = vec![Span::raw(bullet)];
+                                spans.extend(link_spans(&line, &section.links));
+                                lines.push(Line::from(spans));
+                            }
+                        }
+                    }
+                    BlockKind::Note => {
+                        for line in wrap_text(
+                            block.text.as_deref().unwrap_or_default(),
+                            width.saturating_sub(2),
+                        ) {
+                            let mut spans =
+                                vec![Span::styled("▌ ", Style::default().fg(BLUE.c400))];
+                            spans.extend(link_spans(&line, &section.links));
+                            lines.push(Line::from(spans));
+                        }
+                    }
+                    BlockKind::Paragraph => {
+                        for line in wrap_text(block.text.as_deref().unwrap_or_default(), width) {
+                            lines.push(Line::from(link_spans(&line, &section.links)));
+                        }
+                    }
+                }
+
+                lines.push(Line::default());
+            }
+
+            for (link_index, link) in section.links.iter().enumerate() {
+                let selected = self.selected_link == Some((section_index, link_index));
+
+                let style = if selected {
+                    Style::default().fg(BLUE.c300).bold().reversed()
+                } else {
+                    Style::default().fg(GRAY.c500)
+                };
+
+                lines.push(Line::styled(
+                    format!(
+                        "[{}] {} → {}",
+                        link_index + 1,
+                        link.text.as_deref().unwrap_or_default(),
+                        link.url.as_deref().map(minimize_url).unwrap_or_default()
+                    ),
+                    style,
+                ));
+            }
+
+            if !section.links.is_empty() {
+                lines.push(Line::default());
+            }
+        }
+
+        (lines, offsets)
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Tab => {
+                self.focus = match self.focus {
+                    Focus::Contents => Focus::Body,
+                    Focus::Body => Focus::Contents,
+                };
+            }
+            KeyCode::Char('j') if self.focus == Focus::Contents => {
+                self.toc_state.select_next();
+            }
+            KeyCode::Char('k') if self.focus == Focus::Contents => {
+                self.toc_state.select_previous();
+            }
+            KeyCode::Char(' ') | KeyCode::Char('h') if self.focus == Focus::Contents => {
+                self.toggle_selected_section();
+            }
+            KeyCode::Enter | KeyCode::Char('l') if self.focus == Focus::Contents => {
+                self.jump_to_selected_section();
+            }
+            KeyCode::Char('j') => {
+                self.scroll = self.scroll.saturating_add(1);
+            }
+            KeyCode::Char('k') => {
+                self.scroll = self.scroll.saturating_sub(1);
+            }
+            KeyCode::Char('d') => {
+                self.scroll = self.scroll.saturating_add(self.body_height / 2);
+            }
+            KeyCode::Char('u') => {
+                self.scroll = self.scroll.saturating_sub(self.body_height / 2);
+            }
+            KeyCode::Char(']') => {
+                self.select_link(true);
+            }
+            KeyCode::Char('[') => {
+                self.select_link(false);
+            }
+            KeyCode::Enter => {
+                let url = self.selected_link()?.url.clone()?;
+                return Some(Action::Navigate(url));
+            }
+            KeyCode::Char('n') => {
+                let url = self.documentation.as_ref()?.next_page_url.clone()?;
+                return Some(Action::NavigateAs(url, ContentType::Documentation));
+            }
+            KeyCode::Char('p') => {
+                let url = self.documentation.as_ref()?.previous_page_url.clone()?;
+                return Some(Action::NavigateAs(url, ContentType::Documentation));
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn toggle_selected_section(&mut self) {
+        let visible = self.visible_sections();
+
+        let Some(index) = self
+            .toc_state
+            .selected()
+            .and_then(|selected| visible.get(selected))
+        else {
+            return;
+        };
+
+        if !self.collapsed.remove(index) {
+            self.collapsed.insert(*index);
+        }
+    }
+
+    fn jump_to_selected_section(&mut self) {
+        let visible = self.visible_sections();
+
+        if let Some(offset) = self
+            .toc_state
+            .selected()
+            .and_then(|selected| visible.get(selected))
+            .and_then(|index| self.section_offsets.get(*index))
+        {
+            self.scroll = *offset;
+            self.focus = Focus::Body;
+        }
+    }
+
+    fn selected_link(&self) -> Option<&Link> {
+        let (section_index, link_index) = self.selected_link?;
+
+        self.documentation
+            .as_ref()?
+            .sections
+            .get(section_index)?
+            .links
+            .get(link_index)
+    }
+
+    /// Moves the link selection through the document, starting from the
+    /// section in view, and scrolls to the section of the new link.
+    fn select_link(&mut self, forward: bool) {
+        let Some(documentation) = &self.documentation else {
+            return;
+        };
+
+        let links: Vec<(usize, usize)> = documentation
+            .sections
+            .iter()
+            .enumerate()
+            .flat_map(|(section_index, section)| {
+                (0..section.links.len()).map(move |link_index| (section_index, link_index))
+            })
+            .collect();
+
+        if links.is_empty() {
+            return;
+        }
+
+        let position = match self.selected_link {
+            Some(selected) => links
+                .iter()
+                .position(|link| *link == selected)
+                .map(|position| {
+                    if forward {
+                        (position + 1) % links.len()
+                    } else {
+                        (position + links.len() - 1) % links.len()
+                    }
+                }),
+            None => {
+                let current_section = self.current_section().unwrap_or(0);
+                links
+                    .iter()
+                    .position(|(section_index, _)| *section_index >= current_section)
+            }
+        };
+
+        let (section_index, link_index) = links[position.unwrap_or(0)];
+        self.selected_link = Some((section_index, link_index));
+
+        if let Some(offset) = self.section_offsets.get(section_index)
+            && self.current_section() != Some(section_index)
+        {
+            self.scroll = *offset;
+        }
+    }
+}
+
+/// Splits a line of text into spans, underlining the text of known links.
+fn link_spans(line: &str, links: &[Link]) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut rest = line;
+
+    while !rest.is_empty() {
+        let next_link = links
+            .iter()
+            .filter_map(|link| link.text.as_deref())
+            .filter(|text| !text.is_empty())
+            .filter_map(|text| rest.find(text).map(|start| (start, text.len())))
+            .min_by_key(|(start, length)| (*start, usize::MAX - length));
+
+        let Some((start, length)) = next_link else {
+            spans.push(Span::styled(
+                rest.to_string(),
+                Style::default().fg(GRAY.c300),
+            ));
+            break;
+        };
+
+        if start > 0 {
+            spans.push(Span::styled(
+                rest[..start].to_string(),
+                Style::default().fg(GRAY.c300),
+            ));
+        }
+
+        spans.push(Span::styled(
+            rest[start..start + length].to_string(),
+            Style::default().fg(BLUE.c400).underlined(),
+        ));
+
+        rest = &rest[start + length..];
+    }
+
+    spans
+}
+
+fn focus_block(title: &str, focused: bool) -> Block<'static> {
+    let border_style = if focused {
+        Style::default().fg(BLUE.c400)
+    } else {
+        Style::default().fg(GRAY.c600)
+    };
+
+    Block::bordered()
+        .title(format!(" {} ", title))
+        .border_set(border::ROUNDED)
+        .border_style(border_style)
+}
@@ -0,0 +1,287 @@
+use chrono::NaiveDate;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY, GREEN, YELLOW},
+    },
+    text::{Line, Span, Text},
+    widgets::{List, ListItem, ListState, Paragraph, StatefulWidget, Widget},
+};
+use std::collections::HashSet;
+
+use crate::content::event_list::{Event, EventList, to_ics};
+use crate::content::paginated::Paginated;
+use crate::export::write_export;
+use crate::prelude::*;
+use crate::ui::pager::Pager;
+
+/// A line of the agenda: a day heading or an event.
+enum Row {
+    Day(Option<NaiveDate>),
+    Event(usize),
+}
+
+pub struct EventListApp {
+    event_list: Option<EventList>,
+    rows: Vec<Row>,
+    state: ListState,
+    marked: HashSet<String>,
+    pager: Pager,
+}
+
+impl EventListApp {
+    pub fn new() -> Self {
+        Self {
+            event_list: None,
+            rows: Vec::new(),
+            state: ListState::default(),
+            marked: HashSet::new(),
+            pager: Pager::new(),
+        }
+    }
+
+    pub fn run(&mut self, event_list: EventList) {
+        self.pager.reset();
+        self.marked.clear();
+        self.event_list = Some(event_list);
+        self.update_rows();
+        self.state
+            .select(Some(1.min(self.rows.len().saturating_sub(1))));
+    }
+
+    pub fn append(&mut self, page: EventList) {
+        self.pager.reset();
+
+        if let Some(event_list) = &mut self.event_list {
+            event_list.append_page(page);
+        }
+
+        self.update_rows();
+    }
+
+    pub fn next_page_failed(&mut self) {
+        self.pager.reset();
+    }
+
+    fn update_rows(&mut self) {
+        self.rows.clear();
+
+        let Some(event_list) = &self.event_list else {
+            return;
+        };
+
+        for (date, events) in event_list.agenda() {
+            self.rows.push(Row::Day(date));
+            self.rows.extend(events.into_iter().map(Row::Event));
+        }
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(event_list) = &self.event_list else {
+            return;
+        };
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(2),
+                Constraint::Min(3),
+                Constraint::Length(1),
+            ])
+            .split(area);
+
+        let mut header = vec![Span::styled(
+            event_list.title.clone().unwrap_or_default(),
+            Style::default().fg(GRAY.c300).bold(),
+        )];
+
+        if !self.marked.is_empty() {
+            header.push(Span::styled(
+                format!("  {} marked for export", self.marked.len()),
+                Style::default().fg(YELLOW.c400),
+            ));
+        }
+
+        Paragraph::new(Line::from(header)).render(layout[0], buf);
+
+        let items: Vec<ListItem> = self
+            .rows
+            .iter()
+            .map(|row| match row {
+                Row::Day(Some(date)) => ListItem::new(Line::styled(
+                    date.format("%A, %-d %B %Y").to_string(),
+                    Style::default().fg(GREEN.c400).bold(),
+                )),
+                Row::Day(None) => ListItem::new(Line::styled(
+                    "Date unknown",
+                    Style::default().fg(GREEN.c400).bold(),
+                )),
+                Row::Event(index) => {
+                    let event = &event_list.events[*index];
+                    let marked = self.marked.contains(&event.identity());
+
+                    let mut title = vec![
+                        Span::styled(
+                            if marked { "● " } else { "  " },
+                            Style::default().fg(YELLOW.c400),
+                        ),
+                        Span::styled(
+                            format!("{:<13}", time_range(event)),
+                            Style::default().fg(GRAY.c400),
+                        ),
+                        Span::styled(
+                            event.title.clone().unwrap_or_default(),
+                            Style::default().fg(BLUE.c400).bold(),
+                        ),
+                    ];
+
+                    if let Some(organizer) = &event.organizer {
+                        title.push(Span::styled(
+                            format!("  {}", organizer),
+                            Style::default().fg(GRAY.c500),
+                        ));
+                    }
+
+                    let mut lines = vec![Line::from(title)];
+
+                    if let Some(location) = event.location_text() {
+                        lines.push(Line::styled(
+                            format!("{:15}@ {}", "", location),
+                            Style::default().fg(GRAY.c500),
+                        ));
+                    }
+
+                    ListItem::new(Text::from(lines))
+                }
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_symbol(">>")
+            .repeat_highlight_symbol(false);
+
+        StatefulWidget::render(list, layout[1], buf, &mut self.state);
+
+        let footer = if self.pager.is_loading() {
+            Pager::LOADING
+        } else if event_list.next_page_url.is_some() {
+            "Space to mark, x to export as .ics, n to load the next page"
+        } else {
+            "Space to mark, x to export as .ics"
+        };
+
+        Paragraph::new(Line::styled(footer, Style::default().fg(GRAY.c500))).render(layout[2], buf);
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Char('j') => {
+                return self.select_next();
+            }
+            KeyCode::Char('k') => {
+                self.state.select_previous();
+            }
+            KeyCode::Char(' ') => {
+                let identity = self.selected_event()?.identity();
+
+                if !self.marked.remove(&identity) {
+                    self.marked.insert(identity);
+                }
+            }
+            KeyCode::Char('x') => {
+                return Some(self.export());
+            }
+            KeyCode::Char('n') => {
+                return self.load_next_page();
+            }
+            KeyCode::Enter => {
+                let url = self.selected_event()?.url.clone()?;
+                return Some(Action::OpenUsingRenderingEngine(url));
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn selected_event(&self) -> Option<&Event> {
+        match self.rows.get(self.state.selected()?)? {
+            Row::Event(index) => self.event_list.as_ref()?.events.get(*index),
+            Row::Day(_) => None,
+        }
+    }
+
+    /// Exports the marked events, or the selected one when none are marked.
+    fn export(&self) -> Action {
+        let Some(event_list) = &self.event_list else {
+            return Action::ShowNotice("Nothing to export".to_string());
+        };
+
+        let events: Vec<&Event> = if self.marked.is_empty() {
+            self.selected_event().into_iter().collect()
+        } else {
+            event_list
+                .events
+                .iter()
+                .filter(|event| self.marked.contains(&event.identity()))
+                .collect()
+        };
+
+        if !events.iter().any(|event| event.start().is_some()) {
+            return Action::ShowNotice("No events with a known start time to export".to_string());
+        }
+
+        let name = match events.as_slice() {
+            [event] => event.title.clone(),
+            _ => event_list.title.clone(),
+        }
+        .unwrap_or_else(|| "events".to_string());
+
+        match write_export(&name, "ics", &to_ics(&events)) {
+            Ok(path) => Action::ShowNotice(format!("Exported to {}", path.display())),
+            Err(e) => {
+                log::error!("Could not export events: {:?}", e);
+                Action::ShowNotice("Could not export events".to_string())
+            }
+        }
+    }
+
+    fn select_next(&mut self) -> Option<Action> {
+        let next_page_url = self
+            .event_list
+            .as_ref()
+            .and_then(|event_list| event_list.next_page_url.as_deref());
+        self.pager
+            .select_next(&mut self.state, self.rows.len(), next_page_url)
+    }
+
+    fn load_next_page(&mut self) -> Option<Action> {
+        let next_page_url = self.event_list.as_ref()?.next_page_url.as_deref();
+        self.pager.load_next_page(next_page_url)
+    }
+}
+
+fn time_range(event: &Event) -> String {
+    let format_time =
+        |time: Option<chrono::NaiveTime>| time.map(|time| time.format("%H:%M").to_string());
+
+    let start = event.start().and_then(|start| format_time(start.time));
+    let end = event.end().and_then(|end| {
+        let same_day = event.start().is_some_and(|start| start.date == end.date);
+        if same_day {
+            format_time(end.time)
+        } else {
+            Some(end.date.format("→ %-d %b").to_string())
+        }
+    });
+
+    match (start, end) {
+        (Some(start), Some(end)) => format!("{}–{}", start, end),
+        (Some(start), None) => start,
+        (None, Some(end)) => format!("All day {}", end),
+        (None, None) => "All day".to_string(),
+    }
+}
@@ -0,0 +1,62 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY},
+    },
+    text::{Line, Span},
+};
+
+use crate::content::ContentType;
+use crate::content::forum_index::{Breadcrumb, parent_breadcrumb};
+use crate::prelude::*;
+
+/// Breadcrumb trail shown above forum pages. Each crumb is numbered so that
+/// it can be jumped to with the matching digit key.
+pub fn breadcrumb_line(breadcrumbs: &[Breadcrumb]) -> Line<'static> {
+    let mut spans = Vec::new();
+
+    for (index, breadcrumb) in breadcrumbs.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled(" › ", Style::default().fg(GRAY.c600)));
+        }
+
+        if index < 9 && breadcrumb.url.is_some() {
+            spans.push(Span::styled(
+                format!("{} ", index + 1),
+                Style::default().fg(GRAY.c600),
+            ));
+        }
+
+        let style = if index + 1 == breadcrumbs.len() {
+            Style::default().fg(GRAY.c300).bold()
+        } else {
+            Style::default().fg(BLUE.c400)
+        };
+
+        spans.push(Span::styled(
+            breadcrumb.label.clone().unwrap_or_default(),
+            style,
+        ));
+    }
+
+    Line::from(spans)
+}
+
+/// `u` goes up one level and `1`-`9` jump to a breadcrumb. Every level
+/// above a thread is opened as a forum index.
+pub fn handle_breadcrumb_key(breadcrumbs: &[Breadcrumb], code: KeyCode) -> Option<Action> {
+    let breadcrumb = match code {
+        KeyCode::Char('u') => parent_breadcrumb(breadcrumbs)?,
+        KeyCode::Char(c) if c.is_ascii_digit() => {
+            let index = c.to_digit(10)? as usize;
+            breadcrumbs.get(index.checked_sub(1)?)?
+        }
+        _ => return None,
+    };
+
+    breadcrumb
+        .url
+        .clone()
+        .map(|url| Action::NavigateAs(url, ContentType::ForumIndex))
+}
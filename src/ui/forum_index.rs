@@ -0,0 +1,304 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY, GREEN},
+    },
+    text::{Line, Span, Text},
+    widgets::{List, ListItem, ListState, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::content::ContentType;
+use crate::content::forum_index::{ForumIndex, LastPost};
+use crate::content::paginated::Paginated;
+use crate::prelude::*;
+use crate::ui::forum::{breadcrumb_line, handle_breadcrumb_key};
+use crate::ui::pager::Pager;
+
+/// A line of the index: a category heading, a board or a thread.
+enum Row {
+    Category(usize),
+    Board(usize, usize),
+    Thread(usize),
+}
+
+pub struct ForumIndexApp {
+    forum_index: Option<ForumIndex>,
+    rows: Vec<Row>,
+    state: ListState,
+    pager: Pager,
+}
+
+impl ForumIndexApp {
+    pub fn new() -> Self {
+        Self {
+            forum_index: None,
+            rows: Vec::new(),
+            state: ListState::default(),
+            pager: Pager::new(),
+        }
+    }
+
+    pub fn run(&mut self, forum_index: ForumIndex) {
+        self.pager.reset();
+        self.forum_index = Some(forum_index);
+        self.update_rows();
+
+        let first_selectable = self
+            .rows
+            .iter()
+            .position(|row| !matches!(row, Row::Category(_)));
+        self.state.select(first_selectable.or(Some(0)));
+    }
+
+    pub fn append(&mut self, page: ForumIndex) {
+        self.pager.reset();
+
+        if let Some(forum_index) = &mut self.forum_index {
+            forum_index.append_page(page);
+        }
+
+        self.update_rows();
+    }
+
+    pub fn next_page_failed(&mut self) {
+        self.pager.reset();
+    }
+
+    fn update_rows(&mut self) {
+        self.rows.clear();
+
+        let Some(forum_index) = &self.forum_index else {
+            return;
+        };
+
+        for (category_index, category) in forum_index.categories.iter().enumerate() {
+            self.rows.push(Row::Category(category_index));
+
+            for board_index in 0..category.boards.len() {
+                self.rows.push(Row::Board(category_index, board_index));
+            }
+        }
+
+        for thread_index in 0..forum_index.threads.len() {
+            self.rows.push(Row::Thread(thread_index));
+        }
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(forum_index) = &self.forum_index else {
+            return;
+        };
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(2),
+                Constraint::Min(3),
+                Constraint::Length(1),
+            ])
+            .split(area);
+
+        let header = if forum_index.breadcrumbs.is_empty() {
+            Line::styled(
+                forum_index.title.clone().unwrap_or_default(),
+                Style::default().fg(GRAY.c300).bold(),
+            )
+        } else {
+            breadcrumb_line(&forum_index.breadcrumbs)
+        };
+
+        Paragraph::new(header).render(layout[0], buf);
+
+        let items: Vec<ListItem> = self
+            .rows
+            .iter()
+            .map(|row| match row {
+                Row::Category(category_index) => {
+                    let category = &forum_index.categories[*category_index];
+
+                    ListItem::new(Line::styled(
+                        category.name.clone().unwrap_or_default().to_uppercase(),
+                        Style::default().fg(GRAY.c500).bold(),
+                    ))
+                }
+                Row::Board(category_index, board_index) => {
+                    let board = &forum_index.categories[*category_index].boards[*board_index];
+
+                    let mut title = vec![Span::styled(
+                        board.name.clone().unwrap_or_default(),
+                        Style::default().fg(BLUE.c400).bold(),
+                    )];
+
+                    for (count, label) in [
+                        (&board.thread_count, "threads"),
+                        (&board.post_count, "posts"),
+                    ] {
+                        if let Some(count) = count {
+                            title.push(Span::styled(
+                                format!("  {} {}", count, label),
+                                Style::default().fg(GREEN.c500),
+                            ));
+                        }
+                    }
+
+                    let mut lines = vec![Line::from(title)];
+
+                    if let Some(description) = &board.description {
+                        lines.push(Line::styled(
+                            format!("  {}", description),
+                            Style::default().fg(GRAY.c400),
+                        ));
+                    }
+
+                    if let Some(last_post) = &board.last_post {
+                        lines.push(last_post_line(last_post));
+                    }
+
+                    ListItem::new(Text::from(lines))
+                }
+                Row::Thread(thread_index) => {
+                    let thread = &forum_index.threads[*thread_index];
+
+                    let mut title = vec![Span::styled(
+                        thread.title.clone().unwrap_or_default(),
+                        Style::default().fg(BLUE.c400).bold(),
+                    )];
+
+                    if let Some(author) = thread.author.as_ref().and_then(|a| a.name.as_ref()) {
+                        title.push(Span::styled(
+                            format!("  by {}", author),
+                            Style::default().fg(GRAY.c400),
+                        ));
+                    }
+
+                    for (count, label) in [
+                        (&thread.reply_count, "replies"),
+                        (&thread.view_count, "views"),
+                    ] {
+                        if let Some(count) = count {
+                            title.push(Span::styled(
+                                format!("  {} {}", count, label),
+                                Style::default().fg(GREEN.c500),
+                            ));
+                        }
+                    }
+
+                    let mut lines = vec![Line::from(title)];
+
+                    if let Some(last_post) = &thread.last_post {
+                        lines.push(last_post_line(last_post));
+                    }
+
+                    ListItem::new(Text::from(lines))
+                }
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_symbol(">>")
+            .repeat_highlight_symbol(false);
+
+        StatefulWidget::render(list, layout[1], buf, &mut self.state);
+
+        let footer = self
+            .pager
+            .status(forum_index.next_page_url.as_deref())
+            .unwrap_or_default();
+
+        Paragraph::new(Line::styled(footer, Style::default().fg(GRAY.c500))).render(layout[2], buf);
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Char('j') => {
+                return self.select_next();
+            }
+            KeyCode::Char('k') => {
+                self.state.select_previous();
+            }
+            KeyCode::Char('n') => {
+                return self.load_next_page();
+            }
+            KeyCode::Char('l') => {
+                return self
+                    .selected_last_post()?
+                    .url
+                    .clone()
+                    .map(|url| Action::NavigateAs(url, ContentType::ForumThread));
+            }
+            KeyCode::Enter => {
+                return self.open_selected();
+            }
+            code => {
+                return handle_breadcrumb_key(&self.forum_index.as_ref()?.breadcrumbs, code);
+            }
+        }
+
+        None
+    }
+
+    fn open_selected(&self) -> Option<Action> {
+        let forum_index = self.forum_index.as_ref()?;
+
+        match self.rows.get(self.state.selected()?)? {
+            Row::Category(_) => None,
+            Row::Board(category_index, board_index) => forum_index.categories[*category_index]
+                .boards[*board_index]
+                .url
+                .clone()
+                .map(|url| Action::NavigateAs(url, ContentType::ForumIndex)),
+            Row::Thread(thread_index) => forum_index.threads[*thread_index]
+                .url
+                .clone()
+                .map(|url| Action::NavigateAs(url, ContentType::ForumThread)),
+        }
+    }
+
+    fn selected_last_post(&self) -> Option<&LastPost> {
+        let forum_index = self.forum_index.as_ref()?;
+
+        match self.rows.get(self.state.selected()?)? {
+            Row::Category(_) => None,
+            Row::Board(category_index, board_index) => forum_index.categories[*category_index]
+                .boards[*board_index]
+                .last_post
+                .as_ref(),
+            Row::Thread(thread_index) => forum_index.threads[*thread_index].last_post.as_ref(),
+        }
+    }
+
+    fn select_next(&mut self) -> Option<Action> {
+        let next_page_url = self
+            .forum_index
+            .as_ref()
+            .and_then(|forum_index| forum_index.next_page_url.as_deref());
+        self.pager
+            .select_next(&mut self.state, self.rows.len(), next_page_url)
+    }
+
+    fn load_next_page(&mut self) -> Option<Action> {
+        let next_page_url = self.forum_index.as_ref()?.next_page_url.as_deref();
+        self.pager.load_next_page(next_page_url)
+    }
+}
+
+fn last_post_line(last_post: &LastPost) -> Line<'static> {
+    let mut text = String::from("  Last post");
+
+    if let Some(title) = &last_post.title {
+        text.push_str(&format!(" in {}", title));
+    }
+
+    if let Some(author) = &last_post.author {
+        text.push_str(&format!(" by {}", author));
+    }
+
+    if let Some(timestamp) = &last_post.timestamp {
+        text.push_str(&format!(", {}", timestamp));
+    }
+
+    Line::styled(text, Style::default().fg(GRAY.c500))
+}
@@ -0,0 +1,194 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY},
+    },
+    text::{Line, Span, Text},
+    widgets::{List, ListItem, ListState, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::content::ContentType;
+use crate::content::forum_thread::ForumThread;
+use crate::prelude::*;
+use crate::ui::forum::{breadcrumb_line, handle_breadcrumb_key};
+use crate::ui::pager::Pager;
+use crate::ui::text::wrap_text;
+
+pub struct ForumThreadApp {
+    forum_thread: Option<ForumThread>,
+    state: ListState,
+    pager: Pager,
+}
+
+impl ForumThreadApp {
+    pub fn new() -> Self {
+        Self {
+            forum_thread: None,
+            state: ListState::default(),
+            pager: Pager::new(),
+        }
+    }
+
+    pub fn run(&mut self, forum_thread: ForumThread) {
+        self.pager.reset();
+        self.state.select(Some(0));
+        self.forum_thread = Some(forum_thread);
+    }
+
+    pub fn append(&mut self, page: ForumThread) {
+        self.pager.reset();
+
+        if let Some(forum_thread) = &mut self.forum_thread {
+            forum_thread.append_page(page);
+        }
+    }
+
+    pub fn next_page_failed(&mut self) {
+        self.pager.reset();
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(forum_thread) = &self.forum_thread else {
+            return;
+        };
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(1),
+                Constraint::Length(2),
+                Constraint::Min(3),
+                Constraint::Length(1),
+            ])
+            .split(area);
+
+        Paragraph::new(breadcrumb_line(&forum_thread.breadcrumbs)).render(layout[0], buf);
+
+        let mut title = vec![Span::styled(
+            forum_thread.title.clone().unwrap_or_default(),
+            Style::default().fg(GRAY.c300).bold(),
+        )];
+
+        if let (Some(current_page), Some(page_count)) =
+            (forum_thread.current_page, forum_thread.page_count)
+        {
+            title.push(Span::styled(
+                format!("  page {} of {}", current_page, page_count),
+                Style::default().fg(GRAY.c500),
+            ));
+        }
+
+        Paragraph::new(Line::from(title)).render(layout[1], buf);
+
+        let width = layout[2].width.saturating_sub(4) as usize;
+
+        let items: Vec<ListItem> = forum_thread
+            .posts
+            .iter()
+            .map(|post| {
+                let mut heading = vec![Span::styled(
+                    post.author
+                        .as_ref()
+                        .and_then(|author| author.name.clone())
+                        .unwrap_or_else(|| "Anonymous".to_string()),
+                    Style::default().fg(BLUE.c400).bold(),
+                )];
+
+                if let Some(timestamp) = &post.timestamp {
+                    heading.push(Span::styled(
+                        format!("  {}", timestamp),
+                        Style::default().fg(GRAY.c500),
+                    ));
+                }
+
+                let mut lines = vec![Line::from(heading)];
+
+                for quote in &post.quotes {
+                    if let Some(author) = &quote.author {
+                        lines.push(Line::styled(
+                            format!("│ {} wrote:", author),
+                            Style::default().fg(GRAY.c500).italic(),
+                        ));
+                    }
+
+                    for line in wrap_text(
+                        quote.text.as_deref().unwrap_or_default(),
+                        width.saturating_sub(2),
+                    ) {
+                        lines.push(Line::styled(
+                            format!("│ {}", line),
+                            Style::default().fg(GRAY.c500),
+                        ));
+                    }
+                }
+
+                for line in wrap_text(post.content.as_deref().unwrap_or_default(), width) {
+                    lines.push(Line::styled(line, Style::default().fg(GRAY.c300)));
+                }
+
+                lines.push(Line::from(""));
+
+                ListItem::new(Text::from(lines))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_symbol(">>")
+            .repeat_highlight_symbol(false);
+
+        StatefulWidget::render(list, layout[2], buf, &mut self.state);
+
+        let footer = self
+            .pager
+            .status(forum_thread.next_page_url.as_deref())
+            .unwrap_or_default();
+
+        Paragraph::new(Line::styled(footer, Style::default().fg(GRAY.c500))).render(layout[3], buf);
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Char('j') => {
+                return self.select_next();
+            }
+            KeyCode::Char('k') => {
+                self.state.select_previous();
+            }
+            KeyCode::Char('n') => {
+                return self.load_next_page();
+            }
+            KeyCode::Char('p') => {
+                let url = self.forum_thread.as_ref()?.previous_page_url.clone()?;
+                return Some(Action::NavigateAs(url, ContentType::ForumThread));
+            }
+            KeyCode::Char('a') => {
+                let forum_thread = self.forum_thread.as_ref()?;
+                let post = forum_thread.posts.get(self.state.selected()?)?;
+                let url = post.author.as_ref()?.url.clone()?;
+                return Some(Action::NavigateAs(url, ContentType::Profile));
+            }
+            code => {
+                return handle_breadcrumb_key(&self.forum_thread.as_ref()?.breadcrumbs, code);
+            }
+        }
+
+        None
+    }
+
+    fn select_next(&mut self) -> Option<Action> {
+        let forum_thread = self.forum_thread.as_ref()?;
+        self.pager.select_next(
+            &mut self.state,
+            forum_thread.posts.len(),
+            forum_thread.next_page_url.as_deref(),
+        )
+    }
+
+    fn load_next_page(&mut self) -> Option<Action> {
+        let next_page_url = self.forum_thread.as_ref()?.next_page_url.as_deref();
+        self.pager.load_next_page(next_page_url)
+    }
+}
@@ -0,0 +1,391 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY, GREEN, YELLOW},
+    },
+    text::{Line, Span},
+    widgets::{Cell, Paragraph, Row, StatefulWidget, Table, TableState, Widget},
+};
+
+use crate::content::ContentType;
+use crate::content::job_list::{JobFilters, JobList, JobSummary, format_salary};
+use crate::content::paginated::Paginated;
+use crate::content::product_list::parse_number;
+use crate::line_editor::LineEditor;
+use crate::prelude::*;
+use crate::ui::pager::Pager;
+
+#[derive(Clone, Copy)]
+enum FilterField {
+    Location,
+    MinSalary,
+}
+
+impl FilterField {
+    fn label(self) -> &'static str {
+        match self {
+            FilterField::Location => "Location contains",
+            FilterField::MinSalary => "Minimum yearly salary",
+        }
+    }
+}
+
+pub struct JobListApp {
+    job_list: Option<JobList>,
+    filters: JobFilters,
+    /// Indices of the jobs that pass the filters.
+    visible: Vec<usize>,
+    state: TableState,
+    /// Filter being typed in, if any.
+    editing: Option<(FilterField, LineEditor)>,
+    pager: Pager,
+}
+
+impl JobListApp {
+    pub fn new() -> Self {
+        Self {
+            job_list: None,
+            filters: JobFilters::default(),
+            visible: Vec::new(),
+            state: TableState::default(),
+            editing: None,
+            pager: Pager::new(),
+        }
+    }
+
+    /// Shows a listing, applying the filters saved for its board.
+    pub fn run(&mut self, job_list: JobList) {
+        self.pager.reset();
+        self.editing = None;
+        self.filters = job_list
+            .board
+            .as_deref()
+            .and_then(JobFilters::load_saved)
+            .unwrap_or_default();
+        self.job_list = Some(job_list);
+        self.apply_filters();
+        self.state.select(Some(0));
+    }
+
+    pub fn append(&mut self, page: JobList) {
+        self.pager.reset();
+
+        if let Some(job_list) = &mut self.job_list {
+            job_list.append_page(page);
+        }
+
+        self.apply_filters();
+    }
+
+    /// Replaces the jobs with a refreshed listing, keeping the current
+    /// filters. Returns the number of new jobs that pass them.
+    pub fn merge(&mut self, latest: JobList) -> usize {
+        let added = match &self.job_list {
+            Some(previous) => latest
+                .jobs
+                .iter()
+                .filter(|job| self.filters.matches(job))
+                .filter(|job| !previous.jobs.iter().any(|existing| existing.url == job.url))
+                .count(),
+            None => 0,
+        };
+
+        self.job_list = Some(latest);
+        self.apply_filters();
+
+        added
+    }
+
+    pub fn next_page_failed(&mut self) {
+        self.pager.reset();
+    }
+
+    fn apply_filters(&mut self) {
+        let selected_url = self.selected_job().and_then(|job| job.url.clone());
+
+        self.visible = self
+            .job_list
+            .as_ref()
+            .map(|job_list| {
+                job_list
+                    .jobs
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, job)| self.filters.matches(job))
+                    .map(|(index, _)| index)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let index = selected_url.and_then(|url| {
+            let job_list = self.job_list.as_ref()?;
+            self.visible
+                .iter()
+                .position(|index| job_list.jobs[*index].url.as_ref() == Some(&url))
+        });
+
+        self.state.select(index.or(Some(0)));
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(job_list) = &self.job_list else {
+            return;
+        };
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Min(3),
+                Constraint::Length(1),
+            ])
+            .split(area);
+
+        Paragraph::new(Line::from(vec![
+            Span::styled(
+                job_list.title.clone().unwrap_or_default(),
+                Style::default().fg(GRAY.c300).bold(),
+            ),
+            Span::styled(
+                format!("  {} of {} jobs", self.visible.len(), job_list.jobs.len()),
+                Style::default().fg(GRAY.c500),
+            ),
+        ]))
+        .render(layout[0], buf);
+
+        let filter_line = match &self.editing {
+            Some((field, editor)) => {
+                let text = editor.text();
+                let (before, after): (String, String) = (
+                    text.chars().take(editor.cursor()).collect(),
+                    text.chars().skip(editor.cursor()).collect(),
+                );
+
+                Line::from(vec![
+                    Span::styled(
+                        format!("{}: ", field.label()),
+                        Style::default().fg(BLUE.c400),
+                    ),
+                    Span::styled(before, Style::default().fg(GRAY.c200)),
+                    Span::styled("█", Style::default().fg(GRAY.c400)),
+                    Span::styled(after, Style::default().fg(GRAY.c200)),
+                ])
+            }
+            None => Line::from(vec![
+                Span::styled("Filters: ", Style::default().fg(BLUE.c400)),
+                Span::styled(self.filters.describe(), Style::default().fg(GRAY.c300)),
+            ]),
+        };
+
+        Paragraph::new(filter_line).render(layout[1], buf);
+
+        let rows: Vec<Row> = self
+            .visible
+            .iter()
+            .map(|index| {
+                let job = &job_list.jobs[*index];
+
+                let mut location = vec![Span::styled(
+                    job.location.clone().unwrap_or_default(),
+                    Style::default().fg(GRAY.c400),
+                )];
+
+                if job.remote == Some(true) {
+                    location.push(Span::styled(" remote", Style::default().fg(GREEN.c500)));
+                }
+
+                Row::new(vec![
+                    Cell::from(job.title.clone().unwrap_or_default())
+                        .style(Style::default().fg(GRAY.c300)),
+                    Cell::from(job.company.clone().unwrap_or_default())
+                        .style(Style::default().fg(BLUE.c400)),
+                    Cell::from(Line::from(location)),
+                    Cell::from(job.salary.as_ref().map(format_salary).unwrap_or_default())
+                        .style(Style::default().fg(GREEN.c400)),
+                    Cell::from(job.posted_date.clone().unwrap_or_default())
+                        .style(Style::default().fg(YELLOW.c500)),
+                ])
+            })
+            .collect();
+
+        let header = Row::new(vec!["Title", "Company", "Location", "Salary", "Posted"])
+            .style(Style::default().fg(BLUE.c400).bold());
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(32),
+                Constraint::Percentage(18),
+                Constraint::Percentage(20),
+                Constraint::Percentage(18),
+                Constraint::Percentage(12),
+            ],
+        )
+        .header(header)
+        .highlight_symbol(">>")
+        .row_highlight_style(Style::default().bold());
+
+        StatefulWidget::render(table, layout[2], buf, &mut self.state);
+
+        let footer = if self.editing.is_some() {
+            "Enter to apply the filter, empty to remove it, Esc to cancel"
+        } else if self.pager.is_loading() {
+            Pager::LOADING
+        } else {
+            "L location, r remote, m salary, c clear, s save filters for this board, a apply"
+        };
+
+        Paragraph::new(Line::styled(footer, Style::default().fg(GRAY.c500))).render(layout[3], buf);
+    }
+
+    /// Whether a filter is being typed, so that every key belongs to it.
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        if self.editing.is_some() {
+            self.handle_editing_key_event(key_event);
+            return None;
+        }
+
+        match key_event.code {
+            KeyCode::Char('j') => {
+                return self.select_next();
+            }
+            KeyCode::Char('k') => {
+                self.state.select_previous();
+            }
+            KeyCode::Char('L') => {
+                let mut editor = LineEditor::new();
+                editor.set_text(self.filters.location.as_deref().unwrap_or_default());
+                self.editing = Some((FilterField::Location, editor));
+            }
+            KeyCode::Char('m') => {
+                let mut editor = LineEditor::new();
+                editor.set_text(
+                    &self
+                        .filters
+                        .min_salary
+                        .map(|salary| salary.to_string())
+                        .unwrap_or_default(),
+                );
+                self.editing = Some((FilterField::MinSalary, editor));
+            }
+            KeyCode::Char('r') => {
+                self.filters.remote_only = !self.filters.remote_only;
+                self.apply_filters();
+            }
+            KeyCode::Char('c') => {
+                self.filters = JobFilters::default();
+                self.apply_filters();
+            }
+            KeyCode::Char('s') => {
+                return Some(self.save_filters());
+            }
+            KeyCode::Char('n') => {
+                return self.load_next_page();
+            }
+            KeyCode::Char('a') => {
+                let job = self.selected_job()?;
+                let url = job.apply_url.clone().or_else(|| job.url.clone())?;
+                return Some(Action::Navigate(url));
+            }
+            KeyCode::Enter => {
+                let url = self.selected_job()?.url.clone()?;
+                return Some(Action::NavigateAs(url, ContentType::JobPosting));
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn handle_editing_key_event(&mut self, key_event: KeyEvent) {
+        let Some((field, editor)) = &mut self.editing else {
+            return;
+        };
+
+        match key_event.code {
+            KeyCode::Enter => {
+                let text = editor.text().trim().to_string();
+
+                match field {
+                    FilterField::Location => {
+                        self.filters.location = Some(text).filter(|text| !text.is_empty());
+                    }
+                    FilterField::MinSalary => {
+                        self.filters.min_salary = parse_salary(&text);
+                    }
+                }
+
+                self.editing = None;
+                self.apply_filters();
+            }
+            KeyCode::Esc => self.editing = None,
+            KeyCode::Backspace => editor.backspace(),
+            KeyCode::Delete => editor.delete(),
+            KeyCode::Left => editor.move_left(),
+            KeyCode::Right => editor.move_right(),
+            KeyCode::Char(ch) => editor.insert_char(ch),
+            _ => {}
+        }
+    }
+
+    fn save_filters(&self) -> Action {
+        let Some(board) = self
+            .job_list
+            .as_ref()
+            .and_then(|job_list| job_list.board.as_deref())
+        else {
+            return Action::ShowNotice("Could not tell which board this is".to_string());
+        };
+
+        match self.filters.save(board) {
+            Ok(()) if self.filters.is_empty() => {
+                Action::ShowNotice(format!("Removed saved filters for {}", board))
+            }
+            Ok(()) => Action::ShowNotice(format!("Saved filters for {}", board)),
+            Err(e) => {
+                log::error!("Could not save job filters: {:?}", e);
+                Action::ShowNotice("Could not save filters".to_string())
+            }
+        }
+    }
+
+    fn selected_job(&self) -> Option<&JobSummary> {
+        let job_list = self.job_list.as_ref()?;
+        let index = self.visible.get(self.state.selected()?)?;
+
+        job_list.jobs.get(*index)
+    }
+
+    fn select_next(&mut self) -> Option<Action> {
+        let next_page_url = self
+            .job_list
+            .as_ref()
+            .and_then(|job_list| job_list.next_page_url.as_deref());
+        self.pager
+            .select_next(&mut self.state, self.visible.len(), next_page_url)
+    }
+
+    fn load_next_page(&mut self) -> Option<Action> {
+        let next_page_url = self.job_list.as_ref()?.next_page_url.as_deref();
+        self.pager.load_next_page(next_page_url)
+    }
+}
+
+/// Reads salaries typed as `120000`, `120,000` or `120k`.
+fn parse_salary(text: &str) -> Option<f64> {
+    let amount = parse_number(text)?;
+
+    if text.trim_end().to_lowercase().ends_with('k') {
+        Some(amount * 1000.0)
+    } else {
+        Some(amount)
+    }
+}
@@ -0,0 +1,136 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY, GREEN, YELLOW},
+    },
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget, Wrap},
+};
+
+use crate::content::job_list::format_salary;
+use crate::content::job_posting::JobPosting;
+use crate::prelude::*;
+
+pub struct JobPostingApp {
+    job_posting: Option<JobPosting>,
+    description_scroll: u16,
+}
+
+impl JobPostingApp {
+    pub fn new() -> Self {
+        Self {
+            job_posting: None,
+            description_scroll: 0,
+        }
+    }
+
+    pub fn run(&mut self, job_posting: JobPosting) {
+        self.description_scroll = 0;
+        self.job_posting = Some(job_posting);
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(job_posting) = &self.job_posting else {
+            return;
+        };
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(4),
+                Constraint::Min(5),
+                Constraint::Length(1),
+            ])
+            .split(area);
+
+        let mut location = vec![Span::styled(
+            job_posting.location.clone().unwrap_or_default(),
+            Style::default().fg(GRAY.c400),
+        )];
+
+        if job_posting.remote == Some(true) {
+            location.push(Span::styled("  remote", Style::default().fg(GREEN.c500)));
+        }
+
+        if let Some(employment_type) = &job_posting.employment_type {
+            location.push(Span::styled(
+                format!("  {}", employment_type),
+                Style::default().fg(GRAY.c500),
+            ));
+        }
+
+        let mut terms = vec![Span::styled(
+            job_posting
+                .salary
+                .as_ref()
+                .map(format_salary)
+                .unwrap_or_else(|| "Salary not stated".to_string()),
+            Style::default().fg(GREEN.c400).bold(),
+        )];
+
+        if let Some(posted_date) = &job_posting.posted_date {
+            terms.push(Span::styled(
+                format!("  posted {}", posted_date),
+                Style::default().fg(YELLOW.c500),
+            ));
+        }
+
+        Paragraph::new(vec![
+            Line::styled(
+                job_posting.title.clone().unwrap_or_default(),
+                Style::default().fg(GRAY.c300).bold(),
+            ),
+            Line::styled(
+                job_posting.company.clone().unwrap_or_default(),
+                Style::default().fg(BLUE.c400),
+            ),
+            Line::from(location),
+            Line::from(terms),
+        ])
+        .render(layout[0], buf);
+
+        Paragraph::new(job_posting.description.clone().unwrap_or_default())
+            .wrap(Wrap { trim: true })
+            .scroll((self.description_scroll, 0))
+            .block(
+                Block::bordered()
+                    .title(" Description ")
+                    .border_set(border::ROUNDED)
+                    .border_style(Style::default().fg(GRAY.c600)),
+            )
+            .render(layout[1], buf);
+
+        let footer = match &job_posting.apply_url {
+            Some(url) => format!("Press a to apply at {}", minimize_url(url)),
+            None => String::new(),
+        };
+
+        Paragraph::new(Line::styled(footer, Style::default().fg(GRAY.c500))).render(layout[2], buf);
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Char('j') => {
+                self.description_scroll = self.description_scroll.saturating_add(1);
+            }
+            KeyCode::Char('k') => {
+                self.description_scroll = self.description_scroll.saturating_sub(1);
+            }
+            KeyCode::Char('a') | KeyCode::Enter => {
+                let url = self.job_posting.as_ref()?.apply_url.clone()?;
+                return Some(Action::Navigate(url));
+            }
+            KeyCode::Char('c') => {
+                let url = self.job_posting.as_ref()?.company_url.clone()?;
+                return Some(Action::Navigate(url));
+            }
+            _ => {}
+        }
+
+        None
+    }
+}
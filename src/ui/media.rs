@@ -0,0 +1,195 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY, GREEN, YELLOW},
+    },
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, ListState, Paragraph, StatefulWidget, Widget, Wrap},
+};
+
+use crate::content::ContentType;
+use crate::content::media::{Media, format_duration, format_views};
+use crate::prelude::*;
+
+pub struct MediaApp {
+    media: Option<Media>,
+    /// Selection in the list made of the media URL followed by the related
+    /// items.
+    state: ListState,
+    description_scroll: u16,
+}
+
+impl MediaApp {
+    pub fn new() -> Self {
+        Self {
+            media: None,
+            state: ListState::default(),
+            description_scroll: 0,
+        }
+    }
+
+    pub fn run(&mut self, media: Media) {
+        self.state.select(Some(0));
+        self.description_scroll = 0;
+        self.media = Some(media);
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(media) = &self.media else {
+            return;
+        };
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(2), Constraint::Min(5)])
+            .split(area);
+
+        let details: Vec<String> = [
+            media.channel.clone(),
+            media
+                .views
+                .map(|views| format!("{} views", format_views(views))),
+            media.publish_date.clone(),
+            media.duration.as_deref().map(format_duration),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        Paragraph::new(vec![
+            Line::styled(
+                media.title.clone().unwrap_or_default(),
+                Style::default().fg(GRAY.c300).bold(),
+            ),
+            Line::styled(details.join(" · "), Style::default().fg(GRAY.c500)),
+        ])
+        .render(layout[0], buf);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(layout[1]);
+
+        let left = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Min(5),
+                Constraint::Length(media.chapters.len().min(10) as u16 + 2),
+            ])
+            .split(columns[0]);
+
+        Paragraph::new(media.description.clone().unwrap_or_default())
+            .wrap(Wrap { trim: true })
+            .scroll((self.description_scroll, 0))
+            .block(titled_block("Description"))
+            .render(left[0], buf);
+
+        let chapters: Vec<ListItem> = media
+            .chapters
+            .iter()
+            .map(|chapter| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:>8}  ", chapter.start_time.as_deref().unwrap_or_default()),
+                        Style::default().fg(YELLOW.c400),
+                    ),
+                    Span::styled(
+                        chapter.title.clone().unwrap_or_default(),
+                        Style::default().fg(GRAY.c300),
+                    ),
+                ]))
+            })
+            .collect();
+
+        Widget::render(
+            List::new(chapters).block(titled_block("Chapters")),
+            left[1],
+            buf,
+        );
+
+        let play = match &media.media_url {
+            Some(url) => Line::from(vec![
+                Span::styled("▶ Play  ", Style::default().fg(GREEN.c400).bold()),
+                Span::styled(minimize_url(url), Style::default().fg(GRAY.c500)),
+            ]),
+            None => Line::styled("No media URL found", Style::default().fg(GRAY.c500)),
+        };
+
+        let mut items = vec![ListItem::new(vec![play, Line::default()])];
+
+        items.extend(media.related.iter().map(|related| {
+            let details: Vec<String> = [
+                related.channel.clone(),
+                related.duration.as_deref().map(format_duration),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+
+            ListItem::new(vec![
+                Line::styled(
+                    related.title.clone().unwrap_or_default(),
+                    Style::default().fg(BLUE.c400),
+                ),
+                Line::styled(details.join(" · "), Style::default().fg(GRAY.c500)),
+            ])
+        }));
+
+        let list = List::new(items)
+            .block(titled_block("Related"))
+            .highlight_symbol(">>")
+            .repeat_highlight_symbol(false);
+
+        StatefulWidget::render(list, columns[1], buf, &mut self.state);
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Char('j') => {
+                self.state.select_next();
+            }
+            KeyCode::Char('k') => {
+                self.state.select_previous();
+            }
+            KeyCode::Char('J') => {
+                self.description_scroll = self.description_scroll.saturating_add(1);
+            }
+            KeyCode::Char('K') => {
+                self.description_scroll = self.description_scroll.saturating_sub(1);
+            }
+            KeyCode::Char('P') => {
+                let url = self.media.as_ref()?.media_url.clone()?;
+                return Some(Action::PlayMedia(url));
+            }
+            KeyCode::Char('c') => {
+                let url = self.media.as_ref()?.channel_url.clone()?;
+                return Some(Action::Navigate(url));
+            }
+            KeyCode::Enter => {
+                let media = self.media.as_ref()?;
+
+                return match self.state.selected()? {
+                    0 => media.media_url.clone().map(Action::PlayMedia),
+                    index => {
+                        let url = media.related.get(index - 1)?.url.clone()?;
+                        Some(Action::NavigateAs(url, ContentType::Media))
+                    }
+                };
+            }
+            _ => {}
+        }
+
+        None
+    }
+}
+
+fn titled_block(title: &str) -> Block<'static> {
+    Block::bordered()
+        .title(format!(" {} ", title))
+        .border_set(border::ROUNDED)
+        .border_style(Style::default().fg(GRAY.c600))
+}
@@ -2,14 +2,61 @@ use crossterm::event::KeyEvent;
 use ratatui::{buffer::Buffer, layout::Rect};
 
 mod digest;
+mod digest_diff;
+mod documentation;
+mod event_list;
+mod forum;
+mod forum_index;
+mod forum_thread;
+mod job_list;
+mod job_posting;
+mod media;
+mod pager;
+mod product;
+mod product_list;
+mod profile;
+mod repository;
+mod schema;
+mod search_results;
+mod table;
+mod text;
 
 use crate::content::{ContentPayload, ContentType};
 use crate::prelude::*;
+use crate::snapshots::Snapshot;
 use digest::DigestApp;
+use documentation::DocumentationApp;
+use event_list::EventListApp;
+use forum_index::ForumIndexApp;
+use forum_thread::ForumThreadApp;
+use job_list::JobListApp;
+use job_posting::JobPostingApp;
+use media::MediaApp;
+use product::ProductApp;
+use product_list::ProductListApp;
+use profile::ProfileApp;
+use repository::RepositoryApp;
+use schema::SchemaApp;
+use search_results::SearchResultsApp;
+use table::TableApp;
 
 pub struct UI {
     content_type: Option<ContentType>,
     digest: Option<DigestApp>,
+    search_results: Option<SearchResultsApp>,
+    product_list: Option<ProductListApp>,
+    product: Option<ProductApp>,
+    profile: Option<ProfileApp>,
+    forum_index: Option<ForumIndexApp>,
+    forum_thread: Option<ForumThreadApp>,
+    event_list: Option<EventListApp>,
+    documentation: Option<DocumentationApp>,
+    table: Option<TableApp>,
+    media: Option<MediaApp>,
+    job_list: Option<JobListApp>,
+    job_posting: Option<JobPostingApp>,
+    repository: Option<RepositoryApp>,
+    schema: Option<SchemaApp>,
 }
 
 impl UI {
@@ -17,6 +64,20 @@ impl UI {
         UI {
             content_type: None,
             digest: None,
+            search_results: None,
+            product_list: None,
+            product: None,
+            profile: None,
+            forum_index: None,
+            forum_thread: None,
+            event_list: None,
+            documentation: None,
+            table: None,
+            media: None,
+            job_list: None,
+            job_posting: None,
+            repository: None,
+            schema: None,
         }
     }
 
@@ -26,6 +87,62 @@ impl UI {
                 self.content_type = Some(ContentType::Digest);
                 self.digest = Some(DigestApp::new());
             }
+            ContentType::SearchResults => {
+                self.content_type = Some(ContentType::SearchResults);
+                self.search_results = Some(SearchResultsApp::new());
+            }
+            ContentType::ProductList => {
+                self.content_type = Some(ContentType::ProductList);
+                self.product_list = Some(ProductListApp::new());
+            }
+            ContentType::Product => {
+                self.content_type = Some(ContentType::Product);
+                self.product = Some(ProductApp::new());
+            }
+            ContentType::Profile => {
+                self.content_type = Some(ContentType::Profile);
+                self.profile = Some(ProfileApp::new());
+            }
+            ContentType::ForumIndex => {
+                self.content_type = Some(ContentType::ForumIndex);
+                self.forum_index = Some(ForumIndexApp::new());
+            }
+            ContentType::ForumThread => {
+                self.content_type = Some(ContentType::ForumThread);
+                self.forum_thread = Some(ForumThreadApp::new());
+            }
+            ContentType::EventList => {
+                self.content_type = Some(ContentType::EventList);
+                self.event_list = Some(EventListApp::new());
+            }
+            ContentType::Documentation => {
+                self.content_type = Some(ContentType::Documentation);
+                self.documentation = Some(DocumentationApp::new());
+            }
+            ContentType::Table => {
+                self.content_type = Some(ContentType::Table);
+                self.table = Some(TableApp::new());
+            }
+            ContentType::Media => {
+                self.content_type = Some(ContentType::Media);
+                self.media = Some(MediaApp::new());
+            }
+            ContentType::JobList => {
+                self.content_type = Some(ContentType::JobList);
+                self.job_list = Some(JobListApp::new());
+            }
+            ContentType::JobPosting => {
+                self.content_type = Some(ContentType::JobPosting);
+                self.job_posting = Some(JobPostingApp::new());
+            }
+            ContentType::Repository => {
+                self.content_type = Some(ContentType::Repository);
+                self.repository = Some(RepositoryApp::new());
+            }
+            ContentType::Custom(custom) => {
+                self.content_type = Some(ContentType::Custom(custom));
+                self.schema = Some(SchemaApp::new());
+            }
         }
     }
 
@@ -33,6 +150,7 @@ impl UI {
         match content_payload {
             ContentPayload::Digest(digest) => {
                 if let Some(app) = &mut self.digest {
+                    self.content_type = Some(ContentType::Digest);
                     app.run(digest);
                 } else {
                     self.set_content_type(ContentType::Digest);
@@ -40,6 +158,192 @@ impl UI {
                     app.run(digest);
                 }
             }
+            ContentPayload::SearchResults(search_results) => {
+                self.set_content_type(ContentType::SearchResults);
+                let app = &mut self.search_results.as_mut().unwrap();
+                app.run(search_results);
+            }
+            ContentPayload::ProductList(product_list) => {
+                self.set_content_type(ContentType::ProductList);
+                let app = &mut self.product_list.as_mut().unwrap();
+                app.run(product_list);
+            }
+            ContentPayload::Product(product) => {
+                self.set_content_type(ContentType::Product);
+                let app = &mut self.product.as_mut().unwrap();
+                app.run(product);
+            }
+            ContentPayload::Profile(profile) => {
+                self.set_content_type(ContentType::Profile);
+                let app = &mut self.profile.as_mut().unwrap();
+                app.run(profile);
+            }
+            ContentPayload::ForumIndex(forum_index) => {
+                self.set_content_type(ContentType::ForumIndex);
+                let app = &mut self.forum_index.as_mut().unwrap();
+                app.run(forum_index);
+            }
+            ContentPayload::ForumThread(forum_thread) => {
+                self.set_content_type(ContentType::ForumThread);
+                let app = &mut self.forum_thread.as_mut().unwrap();
+                app.run(forum_thread);
+            }
+            ContentPayload::EventList(event_list) => {
+                self.set_content_type(ContentType::EventList);
+                let app = &mut self.event_list.as_mut().unwrap();
+                app.run(event_list);
+            }
+            ContentPayload::Documentation(documentation) => {
+                self.set_content_type(ContentType::Documentation);
+                let app = &mut self.documentation.as_mut().unwrap();
+                app.run(documentation);
+            }
+            ContentPayload::Table(table) => {
+                self.set_content_type(ContentType::Table);
+                let app = &mut self.table.as_mut().unwrap();
+                app.run(table);
+            }
+            ContentPayload::Media(media) => {
+                self.set_content_type(ContentType::Media);
+                let app = &mut self.media.as_mut().unwrap();
+                app.run(media);
+            }
+            ContentPayload::JobList(job_list) => {
+                self.set_content_type(ContentType::JobList);
+                let app = &mut self.job_list.as_mut().unwrap();
+                app.run(job_list);
+            }
+            ContentPayload::JobPosting(job_posting) => {
+                self.set_content_type(ContentType::JobPosting);
+                let app = &mut self.job_posting.as_mut().unwrap();
+                app.run(job_posting);
+            }
+            ContentPayload::Repository(repository) => {
+                self.set_content_type(ContentType::Repository);
+                let app = &mut self.repository.as_mut().unwrap();
+                app.run(repository);
+            }
+            ContentPayload::Custom(content) => {
+                self.set_content_type(ContentType::Custom(content.content_type.clone()));
+                let app = &mut self.schema.as_mut().unwrap();
+                app.run(content);
+            }
+        }
+    }
+
+    /// Appends a following page to the current view. A page whose content
+    /// type differs from the view's is dropped and the view stops waiting
+    /// for it.
+    pub fn append(&mut self, content_payload: ContentPayload) {
+        match (&self.content_type, content_payload) {
+            (Some(ContentType::Digest), ContentPayload::Digest(digest)) => {
+                if let Some(app) = &mut self.digest {
+                    app.append(digest);
+                }
+            }
+            (Some(ContentType::SearchResults), ContentPayload::SearchResults(search_results)) => {
+                if let Some(app) = &mut self.search_results {
+                    app.append(search_results);
+                }
+            }
+            (Some(ContentType::ProductList), ContentPayload::ProductList(product_list)) => {
+                if let Some(app) = &mut self.product_list {
+                    app.append(product_list);
+                }
+            }
+            (Some(ContentType::ForumIndex), ContentPayload::ForumIndex(forum_index)) => {
+                if let Some(app) = &mut self.forum_index {
+                    app.append(forum_index);
+                }
+            }
+            (Some(ContentType::ForumThread), ContentPayload::ForumThread(forum_thread)) => {
+                if let Some(app) = &mut self.forum_thread {
+                    app.append(forum_thread);
+                }
+            }
+            (Some(ContentType::EventList), ContentPayload::EventList(event_list)) => {
+                if let Some(app) = &mut self.event_list {
+                    app.append(event_list);
+                }
+            }
+            (Some(ContentType::JobList), ContentPayload::JobList(job_list)) => {
+                if let Some(app) = &mut self.job_list {
+                    app.append(job_list);
+                }
+            }
+            _ => {
+                log::warn!("Next page does not match the current content type");
+                self.next_page_failed();
+            }
+        }
+    }
+
+    /// Merges a background refresh into the current view, returning the
+    /// number of entries that are new. Views without an in-place merge are
+    /// left alone so that a refresh does not reset their scroll position.
+    pub fn merge(&mut self, content_payload: ContentPayload) -> usize {
+        match (&self.content_type, content_payload) {
+            (Some(ContentType::Digest), ContentPayload::Digest(digest)) => match &mut self.digest {
+                Some(app) => app.merge(digest),
+                None => 0,
+            },
+            (Some(ContentType::ProductList), ContentPayload::ProductList(product_list)) => {
+                match &mut self.product_list {
+                    Some(app) => app.merge(product_list),
+                    None => 0,
+                }
+            }
+            (Some(ContentType::JobList), ContentPayload::JobList(job_list)) => {
+                match &mut self.job_list {
+                    Some(app) => app.merge(job_list),
+                    None => 0,
+                }
+            }
+            _ => 0,
+        }
+    }
+
+    /// Whether the current view can take in background refreshes.
+    pub fn can_merge(&self) -> bool {
+        matches!(
+            self.content_type,
+            Some(ContentType::Digest | ContentType::ProductList | ContentType::JobList)
+        )
+    }
+
+    pub fn show_snapshots(&mut self, snapshots: Vec<Snapshot>) {
+        if let Some(app) = &mut self.digest {
+            app.show_snapshots(snapshots);
+        }
+    }
+
+    pub fn next_page_failed(&mut self) {
+        if let Some(app) = &mut self.digest {
+            app.next_page_failed();
+        }
+
+        if let Some(app) = &mut self.search_results {
+            app.next_page_failed();
+        }
+
+        if let Some(app) = &mut self.product_list {
+            app.next_page_failed();
+        }
+
+        if let Some(app) = &mut self.forum_index {
+            app.next_page_failed();
+        }
+
+        if let Some(app) = &mut self.forum_thread {
+            app.next_page_failed();
+        }
+
+        if let Some(app) = &mut self.event_list {
+            app.next_page_failed();
+        }
+
+        if let Some(app) = &mut self.job_list {
+            app.next_page_failed();
         }
     }
 
@@ -50,10 +354,91 @@ impl UI {
                     app.render(area, buf);
                 }
             }
+            Some(ContentType::SearchResults) => {
+                if let Some(app) = &mut self.search_results {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::ProductList) => {
+                if let Some(app) = &mut self.product_list {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::Product) => {
+                if let Some(app) = &mut self.product {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::Profile) => {
+                if let Some(app) = &mut self.profile {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::ForumIndex) => {
+                if let Some(app) = &mut self.forum_index {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::ForumThread) => {
+                if let Some(app) = &mut self.forum_thread {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::EventList) => {
+                if let Some(app) = &mut self.event_list {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::Documentation) => {
+                if let Some(app) = &mut self.documentation {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::Table) => {
+                if let Some(app) = &mut self.table {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::Media) => {
+                if let Some(app) = &mut self.media {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::JobList) => {
+                if let Some(app) = &mut self.job_list {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::JobPosting) => {
+                if let Some(app) = &mut self.job_posting {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::Repository) => {
+                if let Some(app) = &mut self.repository {
+                    app.render(area, buf);
+                }
+            }
+            Some(ContentType::Custom(_)) => {
+                if let Some(app) = &mut self.schema {
+                    app.render(area, buf);
+                }
+            }
             None => {}
         }
     }
 
+    /// Whether the current view is taking text input, in which case it gets
+    /// every key before the app-wide bindings.
+    pub fn is_editing(&self) -> bool {
+        match self.content_type {
+            Some(ContentType::JobList) => {
+                self.job_list.as_ref().is_some_and(JobListApp::is_editing)
+            }
+            _ => false,
+        }
+    }
+
     pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
         match self.content_type {
             Some(ContentType::Digest) => {
@@ -61,6 +446,76 @@ impl UI {
                     return app.handle_key_event(key_event);
                 }
             }
+            Some(ContentType::SearchResults) => {
+                if let Some(app) = &mut self.search_results {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::ProductList) => {
+                if let Some(app) = &mut self.product_list {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::Product) => {
+                if let Some(app) = &mut self.product {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::Profile) => {
+                if let Some(app) = &mut self.profile {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::ForumIndex) => {
+                if let Some(app) = &mut self.forum_index {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::ForumThread) => {
+                if let Some(app) = &mut self.forum_thread {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::EventList) => {
+                if let Some(app) = &mut self.event_list {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::Documentation) => {
+                if let Some(app) = &mut self.documentation {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::Table) => {
+                if let Some(app) = &mut self.table {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::Media) => {
+                if let Some(app) = &mut self.media {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::JobList) => {
+                if let Some(app) = &mut self.job_list {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::JobPosting) => {
+                if let Some(app) = &mut self.job_posting {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::Repository) => {
+                if let Some(app) = &mut self.repository {
+                    return app.handle_key_event(key_event);
+                }
+            }
+            Some(ContentType::Custom(_)) => {
+                if let Some(app) = &mut self.schema {
+                    return app.handle_key_event(key_event);
+                }
+            }
             None => {}
         }
 
@@ -0,0 +1,129 @@
+use ratatui::widgets::{ListState, TableState};
+
+use crate::prelude::*;
+
+/// Selection of a list or table whose rows continue on following pages.
+pub trait Selection {
+    fn selected(&self) -> Option<usize>;
+    fn select(&mut self, index: Option<usize>);
+    fn select_next(&mut self);
+}
+
+impl Selection for ListState {
+    fn selected(&self) -> Option<usize> {
+        ListState::selected(self)
+    }
+
+    fn select(&mut self, index: Option<usize>) {
+        ListState::select(self, index);
+    }
+
+    fn select_next(&mut self) {
+        ListState::select_next(self);
+    }
+}
+
+impl Selection for TableState {
+    fn selected(&self) -> Option<usize> {
+        TableState::selected(self)
+    }
+
+    fn select(&mut self, index: Option<usize>) {
+        TableState::select(self, index);
+    }
+
+    fn select_next(&mut self) {
+        TableState::select_next(self);
+    }
+}
+
+/// Keeps track of the following page of a view being loaded, so that it is
+/// asked for only once.
+#[derive(Default)]
+pub struct Pager {
+    loading: bool,
+}
+
+impl Pager {
+    pub const LOADING: &'static str = "Loading next page...";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops waiting for a page, once it arrived or could not be loaded, or
+    /// when other content is shown.
+    pub fn reset(&mut self) {
+        self.loading = false;
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// Asks for the page at `next_page_url` unless one is already loading.
+    pub fn load_next_page(&mut self, next_page_url: Option<&str>) -> Option<Action> {
+        if self.loading {
+            return None;
+        }
+
+        let url = next_page_url?.to_string();
+        self.loading = true;
+
+        Some(Action::LoadNextPage(url))
+    }
+
+    /// Moves the selection down among `row_count` rows, asking for the next
+    /// page once it reaches the last one.
+    pub fn select_next(
+        &mut self,
+        state: &mut impl Selection,
+        row_count: usize,
+        next_page_url: Option<&str>,
+    ) -> Option<Action> {
+        state.select_next();
+
+        let selected = state.selected()?;
+
+        if selected + 1 >= row_count {
+            state.select(Some(row_count.saturating_sub(1)));
+            return self.load_next_page(next_page_url);
+        }
+
+        None
+    }
+
+    /// Line telling that a page is loading or that another one can be.
+    pub fn status(&self, next_page_url: Option<&str>) -> Option<&'static str> {
+        if self.loading {
+            Some(Self::LOADING)
+        } else if next_page_url.is_some() {
+            Some("Press n to load the next page")
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asks_for_the_next_page_once_at_the_last_row() {
+        let mut pager = Pager::new();
+        let mut state = ListState::default().with_selected(Some(0));
+
+        assert!(pager.select_next(&mut state, 3, Some("?p=2")).is_none());
+        assert!(matches!(
+            pager.select_next(&mut state, 3, Some("?p=2")),
+            Some(Action::LoadNextPage(url)) if url == "?p=2"
+        ));
+        assert!(pager.select_next(&mut state, 3, Some("?p=2")).is_none());
+        assert_eq!(state.selected(), Some(2));
+        assert_eq!(pager.status(Some("?p=2")), Some(Pager::LOADING));
+
+        pager.reset();
+        assert!(pager.load_next_page(Some("?p=2")).is_some());
+    }
+}
@@ -0,0 +1,227 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY, GREEN, YELLOW},
+    },
+    symbols::border,
+    text::{Line, Span},
+    widgets::{
+        Block, List, ListItem, ListState, Paragraph, Row, StatefulWidget, Table, Widget, Wrap,
+    },
+};
+
+use crate::content::product::Product;
+use crate::content::product_list::{format_price, format_rating};
+use crate::prelude::*;
+
+pub struct ProductApp {
+    product: Option<Product>,
+    variant_list: ListState,
+    description_scroll: u16,
+}
+
+impl ProductApp {
+    pub fn new() -> Self {
+        Self {
+            product: None,
+            variant_list: ListState::default(),
+            description_scroll: 0,
+        }
+    }
+
+    pub fn run(&mut self, product: Product) {
+        self.variant_list.select(if product.variants.is_empty() {
+            None
+        } else {
+            Some(0)
+        });
+        self.description_scroll = 0;
+        self.product = Some(product);
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(product) = &self.product else {
+            return;
+        };
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(2), Constraint::Min(5)])
+            .split(area);
+
+        let mut summary = vec![Span::styled(
+            format_price(product.price, product.currency.as_deref()),
+            Style::default().fg(GREEN.c400).bold(),
+        )];
+
+        if let Some(availability) = &product.availability {
+            summary.push(Span::styled(
+                format!("  {}", availability),
+                Style::default().fg(GRAY.c400),
+            ));
+        }
+
+        if let Some(reviews_summary) = &product.reviews_summary {
+            summary.push(Span::styled(
+                format!(
+                    "  {}",
+                    format_rating(reviews_summary.rating, reviews_summary.rating_scale)
+                ),
+                Style::default().fg(YELLOW.c400),
+            ));
+
+            if let Some(review_count) = reviews_summary.review_count {
+                summary.push(Span::styled(
+                    format!(" ({} reviews)", review_count),
+                    Style::default().fg(GRAY.c500),
+                ));
+            }
+        }
+
+        Paragraph::new(vec![
+            Line::styled(
+                product.name.clone().unwrap_or_default(),
+                Style::default().fg(GRAY.c300).bold(),
+            ),
+            Line::from(summary),
+        ])
+        .render(layout[0], buf);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(layout[1]);
+
+        let left = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Min(5), Constraint::Length(6)])
+            .split(columns[0]);
+
+        let right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Min(5),
+                Constraint::Length(product.variants.len().min(8) as u16 + 2),
+            ])
+            .split(columns[1]);
+
+        Paragraph::new(product.description.clone().unwrap_or_default())
+            .wrap(Wrap { trim: true })
+            .scroll((self.description_scroll, 0))
+            .block(titled_block("Description"))
+            .render(left[0], buf);
+
+        let reviews = product
+            .reviews_summary
+            .as_ref()
+            .and_then(|reviews_summary| reviews_summary.summary.clone())
+            .unwrap_or_else(|| "No review summary".to_string());
+
+        Paragraph::new(reviews)
+            .wrap(Wrap { trim: true })
+            .block(titled_block("Reviews"))
+            .render(left[1], buf);
+
+        let spec_rows: Vec<Row> = product
+            .specs
+            .iter()
+            .map(|spec| {
+                Row::new(vec![
+                    Span::styled(
+                        spec.name.clone().unwrap_or_default(),
+                        Style::default().fg(GRAY.c400),
+                    ),
+                    Span::styled(
+                        spec.value.clone().unwrap_or_default(),
+                        Style::default().fg(GRAY.c300),
+                    ),
+                ])
+            })
+            .collect();
+
+        Widget::render(
+            Table::new(
+                spec_rows,
+                [Constraint::Percentage(40), Constraint::Percentage(60)],
+            )
+            .block(titled_block("Specifications")),
+            right[0],
+            buf,
+        );
+
+        let variant_items: Vec<ListItem> = product
+            .variants
+            .iter()
+            .map(|variant| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        variant.name.clone().unwrap_or_default(),
+                        Style::default().fg(BLUE.c400),
+                    ),
+                    Span::styled(
+                        format!(
+                            "  {}",
+                            format_price(variant.price, product.currency.as_deref())
+                        ),
+                        Style::default().fg(GREEN.c500),
+                    ),
+                    Span::styled(
+                        format!("  {}", variant.availability.as_deref().unwrap_or_default()),
+                        Style::default().fg(GRAY.c500),
+                    ),
+                ]))
+            })
+            .collect();
+
+        let variant_list = List::new(variant_items)
+            .block(titled_block("Variants"))
+            .highlight_symbol(">>");
+
+        StatefulWidget::render(variant_list, right[1], buf, &mut self.variant_list);
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Char('j') => {
+                self.variant_list.select_next();
+            }
+            KeyCode::Char('k') => {
+                self.variant_list.select_previous();
+            }
+            KeyCode::Char('J') => {
+                self.description_scroll = self.description_scroll.saturating_add(1);
+            }
+            KeyCode::Char('K') => {
+                self.description_scroll = self.description_scroll.saturating_sub(1);
+            }
+            KeyCode::Char('v') => {
+                let url = self
+                    .product
+                    .as_ref()?
+                    .reviews_summary
+                    .as_ref()?
+                    .url
+                    .clone()?;
+                return Some(Action::OpenUsingRenderingEngine(url));
+            }
+            KeyCode::Enter => {
+                let product = self.product.as_ref()?;
+                let variant = product.variants.get(self.variant_list.selected()?)?;
+                return variant.url.clone().map(Action::OpenUsingRenderingEngine);
+            }
+            _ => {}
+        }
+
+        None
+    }
+}
+
+fn titled_block(title: &str) -> Block<'static> {
+    Block::bordered()
+        .title(format!(" {} ", title))
+        .border_set(border::ROUNDED)
+        .border_style(Style::default().fg(GRAY.c600))
+}
@@ -0,0 +1,242 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY, GREEN, RED, YELLOW},
+    },
+    text::{Line, Span},
+    widgets::{Cell, Paragraph, Row, StatefulWidget, Table, TableState, Widget},
+};
+
+use crate::content::paginated::Paginated;
+use crate::content::product_list::{ProductList, ProductOrder, format_price, format_rating};
+use crate::prelude::*;
+use crate::ui::pager::Pager;
+
+pub struct ProductListApp {
+    product_list: Option<ProductList>,
+    order: ProductOrder,
+    sorted_indices: Vec<usize>,
+    state: TableState,
+    pager: Pager,
+}
+
+impl ProductListApp {
+    pub fn new() -> Self {
+        Self {
+            product_list: None,
+            order: ProductOrder::Listed,
+            sorted_indices: Vec::new(),
+            state: TableState::default(),
+            pager: Pager::new(),
+        }
+    }
+
+    pub fn run(&mut self, product_list: ProductList) {
+        self.pager.reset();
+        self.product_list = Some(product_list);
+        self.sort();
+        self.state.select(Some(0));
+    }
+
+    pub fn append(&mut self, page: ProductList) {
+        self.pager.reset();
+
+        if let Some(product_list) = &mut self.product_list {
+            product_list.append_page(page);
+        }
+
+        self.sort();
+    }
+
+    /// Replaces the products with a refreshed listing, keeping the selected
+    /// product selected. Returns the number of products that are new.
+    pub fn merge(&mut self, latest: ProductList) -> usize {
+        let selected_url = self.selected_url();
+
+        let added = match &self.product_list {
+            Some(previous) => latest
+                .products
+                .iter()
+                .filter(|product| {
+                    !previous
+                        .products
+                        .iter()
+                        .any(|existing| existing.url == product.url)
+                })
+                .count(),
+            None => 0,
+        };
+
+        self.product_list = Some(latest);
+        self.sort();
+
+        let index = selected_url.and_then(|url| {
+            let product_list = self.product_list.as_ref()?;
+            self.sorted_indices
+                .iter()
+                .position(|index| product_list.products[*index].url.as_ref() == Some(&url))
+        });
+
+        self.state.select(index.or(Some(0)));
+
+        added
+    }
+
+    pub fn next_page_failed(&mut self) {
+        self.pager.reset();
+    }
+
+    fn sort(&mut self) {
+        self.sorted_indices = self
+            .product_list
+            .as_ref()
+            .map(|product_list| product_list.sorted_indices(self.order))
+            .unwrap_or_default();
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(product_list) = &self.product_list else {
+            return;
+        };
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(1),
+                Constraint::Min(3),
+                Constraint::Length(1),
+            ])
+            .split(area);
+
+        Paragraph::new(Line::from(vec![
+            Span::styled(
+                product_list.title.clone().unwrap_or_default(),
+                Style::default().fg(GRAY.c300).bold(),
+            ),
+            Span::styled(
+                format!(
+                    "  {} products, sorted by {} (s to change)",
+                    product_list.products.len(),
+                    self.order.as_str()
+                ),
+                Style::default().fg(GRAY.c500),
+            ),
+        ]))
+        .render(layout[0], buf);
+
+        let rows: Vec<Row> = self
+            .sorted_indices
+            .iter()
+            .map(|index| {
+                let product = &product_list.products[*index];
+
+                let availability_style = match &product.availability {
+                    Some(availability) if is_unavailable(availability) => {
+                        Style::default().fg(RED.c400)
+                    }
+                    _ => Style::default().fg(GREEN.c500),
+                };
+
+                Row::new(vec![
+                    Cell::from(product.name.clone().unwrap_or_default())
+                        .style(Style::default().fg(GRAY.c300)),
+                    Cell::from(format_price(product.price, product.currency.as_deref()))
+                        .style(Style::default().fg(GREEN.c400).bold()),
+                    Cell::from(format_rating(product.rating, product.rating_scale))
+                        .style(Style::default().fg(YELLOW.c400)),
+                    Cell::from(product.availability.clone().unwrap_or_default())
+                        .style(availability_style),
+                ])
+            })
+            .collect();
+
+        let header = Row::new(vec!["Name", "Price", "Rating", "Availability"])
+            .style(Style::default().fg(BLUE.c400).bold());
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(50),
+                Constraint::Percentage(15),
+                Constraint::Percentage(15),
+                Constraint::Percentage(20),
+            ],
+        )
+        .header(header)
+        .highlight_symbol(">>")
+        .row_highlight_style(Style::default().bold());
+
+        StatefulWidget::render(table, layout[1], buf, &mut self.state);
+
+        let footer = self
+            .pager
+            .status(product_list.next_page_url.as_deref())
+            .unwrap_or_default();
+
+        Paragraph::new(Line::styled(footer, Style::default().fg(GRAY.c500))).render(layout[2], buf);
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Char('j') => {
+                return self.select_next();
+            }
+            KeyCode::Char('k') => {
+                self.state.select_previous();
+            }
+            KeyCode::Char('s') => {
+                let selected_url = self.selected_url();
+                self.order = self.order.next();
+                self.sort();
+
+                if let Some(product_list) = &self.product_list {
+                    let index = self.sorted_indices.iter().position(|index| {
+                        selected_url.is_some() && product_list.products[*index].url == selected_url
+                    });
+                    self.state.select(index.or(Some(0)));
+                }
+            }
+            KeyCode::Char('n') => {
+                return self.load_next_page();
+            }
+            KeyCode::Enter => {
+                return self.selected_url().map(Action::Navigate);
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn selected_url(&self) -> Option<String> {
+        let product_list = self.product_list.as_ref()?;
+        let index = self.sorted_indices.get(self.state.selected()?)?;
+
+        product_list.products.get(*index)?.url.clone()
+    }
+
+    fn select_next(&mut self) -> Option<Action> {
+        let next_page_url = self
+            .product_list
+            .as_ref()
+            .and_then(|product_list| product_list.next_page_url.as_deref());
+        self.pager
+            .select_next(&mut self.state, self.sorted_indices.len(), next_page_url)
+    }
+
+    fn load_next_page(&mut self) -> Option<Action> {
+        let next_page_url = self.product_list.as_ref()?.next_page_url.as_deref();
+        self.pager.load_next_page(next_page_url)
+    }
+}
+
+fn is_unavailable(availability: &str) -> bool {
+    let availability = availability.to_lowercase();
+
+    ["out of stock", "unavailable", "sold out", "discontinued"]
+        .iter()
+        .any(|phrase| availability.contains(phrase))
+}
@@ -0,0 +1,242 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY, GREEN},
+    },
+    symbols::border,
+    text::{Line, Span, Text},
+    widgets::{Block, List, ListItem, ListState, Paragraph, StatefulWidget, Widget, Wrap},
+};
+
+use crate::content::profile::Profile;
+use crate::prelude::*;
+
+#[derive(PartialEq)]
+enum Focus {
+    Activity,
+    Links,
+}
+
+pub struct ProfileApp {
+    profile: Option<Profile>,
+    activity_list: ListState,
+    link_list: ListState,
+    focus: Focus,
+}
+
+impl ProfileApp {
+    pub fn new() -> Self {
+        Self {
+            profile: None,
+            activity_list: ListState::default(),
+            link_list: ListState::default(),
+            focus: Focus::Activity,
+        }
+    }
+
+    pub fn run(&mut self, profile: Profile) {
+        self.focus = if profile.recent_activity.is_empty() && !profile.links.is_empty() {
+            Focus::Links
+        } else {
+            Focus::Activity
+        };
+        self.activity_list.select(Some(0));
+        self.link_list.select(Some(0));
+        self.profile = Some(profile);
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(profile) = &self.profile else {
+            return;
+        };
+
+        let bio_height = profile
+            .bio
+            .as_ref()
+            .map_or(0, |bio| (bio.len() as u16 / area.width.max(1) + 1).min(6));
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(1),
+                Constraint::Length(bio_height),
+                Constraint::Length(if profile.stats.is_empty() { 0 } else { 2 }),
+                Constraint::Min(3),
+            ])
+            .split(area);
+
+        let mut name = vec![Span::styled(
+            profile
+                .display_name
+                .clone()
+                .or_else(|| profile.handle.clone())
+                .unwrap_or_else(|| "Unknown user".to_string()),
+            Style::default().fg(GRAY.c300).bold(),
+        )];
+
+        if let Some(handle) = &profile.handle
+            && profile.display_name.is_some()
+        {
+            name.push(Span::styled(
+                format!("  @{}", handle.trim_start_matches('@')),
+                Style::default().fg(GRAY.c500),
+            ));
+        }
+
+        Paragraph::new(Line::from(name)).render(layout[0], buf);
+
+        if let Some(bio) = &profile.bio {
+            Paragraph::new(bio.as_str())
+                .style(Style::default().fg(GRAY.c400))
+                .wrap(Wrap { trim: true })
+                .render(layout[1], buf);
+        }
+
+        let stats: Vec<Span> = profile
+            .stats
+            .iter()
+            .flat_map(|stat| {
+                [
+                    Span::styled(
+                        stat.value.clone().unwrap_or_default(),
+                        Style::default().fg(GREEN.c400).bold(),
+                    ),
+                    Span::styled(
+                        format!(" {}   ", stat.label.as_deref().unwrap_or_default()),
+                        Style::default().fg(GRAY.c500),
+                    ),
+                ]
+            })
+            .collect();
+
+        Paragraph::new(Line::from(stats))
+            .wrap(Wrap { trim: true })
+            .render(layout[2], buf);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(70), Constraint::Percentage(30)])
+            .split(layout[3]);
+
+        let activity_items: Vec<ListItem> = profile
+            .recent_activity
+            .iter()
+            .map(|activity| {
+                let mut title = vec![Span::styled(
+                    activity.title.clone().unwrap_or_default(),
+                    Style::default().fg(GRAY.c300),
+                )];
+
+                if let Some(timestamp) = &activity.timestamp {
+                    title.push(Span::styled(
+                        format!("  {}", timestamp),
+                        Style::default().fg(GRAY.c500),
+                    ));
+                }
+
+                let mut lines = vec![Line::from(title)];
+
+                if let Some(summary) = &activity.summary {
+                    lines.push(Line::styled(
+                        summary.clone(),
+                        Style::default().fg(GRAY.c500),
+                    ));
+                }
+
+                ListItem::new(Text::from(lines))
+            })
+            .collect();
+
+        let activity_list = List::new(activity_items)
+            .block(self.block("Recent activity", Focus::Activity))
+            .highlight_symbol(">>")
+            .repeat_highlight_symbol(false);
+
+        StatefulWidget::render(activity_list, columns[0], buf, &mut self.activity_list);
+
+        let link_items: Vec<ListItem> = profile
+            .links
+            .iter()
+            .map(|link| {
+                ListItem::new(Line::styled(
+                    link.label
+                        .clone()
+                        .or_else(|| link.url.as_deref().map(minimize_url))
+                        .unwrap_or_default(),
+                    Style::default().fg(BLUE.c400),
+                ))
+            })
+            .collect();
+
+        let link_list = List::new(link_items)
+            .block(self.block("Links", Focus::Links))
+            .highlight_symbol(">>");
+
+        StatefulWidget::render(link_list, columns[1], buf, &mut self.link_list);
+    }
+
+    fn block(&self, title: &str, focus: Focus) -> Block<'static> {
+        let border_style = if self.focus == focus {
+            Style::default().fg(BLUE.c400)
+        } else {
+            Style::default().fg(GRAY.c600)
+        };
+
+        Block::bordered()
+            .title(format!(" {} ", title))
+            .border_set(border::ROUNDED)
+            .border_style(border_style)
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Char('j') => {
+                self.selected_list().select_next();
+            }
+            KeyCode::Char('k') => {
+                self.selected_list().select_previous();
+            }
+            KeyCode::Tab | KeyCode::Char('h') | KeyCode::Char('l') => {
+                self.focus = match self.focus {
+                    Focus::Activity => Focus::Links,
+                    Focus::Links => Focus::Activity,
+                };
+            }
+            KeyCode::Enter => {
+                return self.open_selected();
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn selected_list(&mut self) -> &mut ListState {
+        match self.focus {
+            Focus::Activity => &mut self.activity_list,
+            Focus::Links => &mut self.link_list,
+        }
+    }
+
+    fn open_selected(&self) -> Option<Action> {
+        let profile = self.profile.as_ref()?;
+
+        match self.focus {
+            Focus::Activity => profile
+                .recent_activity
+                .get(self.activity_list.selected()?)?
+                .url
+                .clone()
+                .map(Action::Navigate),
+            Focus::Links => profile
+                .links
+                .get(self.link_list.selected()?)?
+                .url
+                .clone()
+                .map(Action::OpenUsingRenderingEngine),
+        }
+    }
+}
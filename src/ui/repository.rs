@@ -0,0 +1,401 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY, GREEN, PURPLE, YELLOW},
+    },
+    symbols::border,
+    text::{Line, Span, Text},
+    widgets::{Block, List, ListItem, ListState, Paragraph, StatefulWidget, Tabs, Widget, Wrap},
+};
+
+use crate::content::ContentType;
+use crate::content::repository::{Repository, format_count};
+use crate::prelude::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tab {
+    Files,
+    Readme,
+    Commits,
+    Issues,
+}
+
+impl Tab {
+    const ALL: [Tab; 4] = [Tab::Files, Tab::Readme, Tab::Commits, Tab::Issues];
+
+    fn title(self) -> &'static str {
+        match self {
+            Tab::Files => "Files",
+            Tab::Readme => "README",
+            Tab::Commits => "Commits",
+            Tab::Issues => "Issues",
+        }
+    }
+
+    fn index(self) -> usize {
+        Tab::ALL.iter().position(|tab| *tab == self).unwrap_or(0)
+    }
+}
+
+pub struct RepositoryApp {
+    repository: Option<Repository>,
+    tab: Tab,
+    file_list: ListState,
+    commit_list: ListState,
+    issue_list: ListState,
+    readme_scroll: u16,
+}
+
+impl RepositoryApp {
+    pub fn new() -> Self {
+        Self {
+            repository: None,
+            tab: Tab::Files,
+            file_list: ListState::default(),
+            commit_list: ListState::default(),
+            issue_list: ListState::default(),
+            readme_scroll: 0,
+        }
+    }
+
+    /// Opens on the first part the page has, so that a commit or issue
+    /// listing does not show an empty file tree.
+    pub fn run(&mut self, repository: Repository) {
+        self.tab = if !repository.files.is_empty() {
+            Tab::Files
+        } else if repository.readme.is_some() {
+            Tab::Readme
+        } else if !repository.commits.is_empty() {
+            Tab::Commits
+        } else if !repository.issues.is_empty() {
+            Tab::Issues
+        } else {
+            Tab::Files
+        };
+        self.file_list.select(Some(0));
+        self.commit_list.select(Some(0));
+        self.issue_list.select(Some(0));
+        self.readme_scroll = 0;
+        self.repository = Some(repository);
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(repository) = &self.repository else {
+            return;
+        };
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Length(1),
+                Constraint::Min(3),
+            ])
+            .split(area);
+
+        let name = match (&repository.owner, &repository.name) {
+            (Some(owner), Some(name)) => format!("{} / {}", owner, name),
+            (None, Some(name)) => name.clone(),
+            (Some(owner), None) => owner.clone(),
+            (None, None) => "Repository".to_string(),
+        };
+
+        let mut title = vec![Span::styled(name, Style::default().fg(GRAY.c300).bold())];
+
+        if let Some(stars) = repository.stars {
+            title.push(Span::styled(
+                format!("  ★ {}", format_count(stars)),
+                Style::default().fg(YELLOW.c400),
+            ));
+        }
+
+        if let Some(forks) = repository.forks {
+            title.push(Span::styled(
+                format!("  ⑂ {}", format_count(forks)),
+                Style::default().fg(GRAY.c400),
+            ));
+        }
+
+        if let Some(branch) = &repository.branch {
+            title.push(Span::styled(
+                format!("  {}", branch),
+                Style::default().fg(PURPLE.c400),
+            ));
+        }
+
+        let languages: Vec<String> = repository
+            .languages
+            .iter()
+            .filter_map(|language| {
+                let name = language.name.as_deref()?;
+
+                Some(match language.percentage {
+                    Some(percentage) => format!("{} {:.1}%", name, percentage),
+                    None => name.to_string(),
+                })
+            })
+            .collect();
+
+        Paragraph::new(vec![
+            Line::from(title),
+            Line::styled(
+                repository.description.clone().unwrap_or_default(),
+                Style::default().fg(GRAY.c400),
+            ),
+            Line::styled(languages.join(" · "), Style::default().fg(GREEN.c500)),
+        ])
+        .render(layout[0], buf);
+
+        let counts = [
+            repository.files.len(),
+            0,
+            repository.commits.len(),
+            repository.issues.len(),
+        ];
+
+        let tab_titles: Vec<String> = Tab::ALL
+            .iter()
+            .zip(counts)
+            .map(|(tab, count)| match tab {
+                Tab::Readme => tab.title().to_string(),
+                _ => format!("{} ({})", tab.title(), count),
+            })
+            .collect();
+
+        Tabs::new(tab_titles)
+            .select(self.tab.index())
+            .style(Style::default().fg(GRAY.c500))
+            .highlight_style(Style::default().fg(BLUE.c400).bold())
+            .render(layout[1], buf);
+
+        match self.tab {
+            Tab::Files => {
+                let items: Vec<ListItem> = repository
+                    .sorted_files()
+                    .iter()
+                    .map(|file| {
+                        let (icon, style) = if file.is_directory() {
+                            ("▸ ", Style::default().fg(BLUE.c400))
+                        } else {
+                            ("  ", Style::default().fg(GRAY.c300))
+                        };
+
+                        ListItem::new(Line::from(vec![
+                            Span::styled(
+                                format!("{}{:32}", icon, file.name.as_deref().unwrap_or_default()),
+                                style,
+                            ),
+                            Span::styled(
+                                format!(
+                                    " {:40} ",
+                                    file.last_commit_message.as_deref().unwrap_or_default()
+                                ),
+                                Style::default().fg(GRAY.c500),
+                            ),
+                            Span::styled(
+                                file.updated.clone().unwrap_or_default(),
+                                Style::default().fg(GRAY.c600),
+                            ),
+                        ]))
+                    })
+                    .collect();
+
+                let path = repository
+                    .path
+                    .as_deref()
+                    .filter(|path| !path.is_empty())
+                    .unwrap_or("/");
+
+                let list = List::new(items)
+                    .block(titled_block(path))
+                    .highlight_symbol(">>");
+
+                StatefulWidget::render(list, layout[2], buf, &mut self.file_list);
+            }
+            Tab::Readme => {
+                Paragraph::new(
+                    repository
+                        .readme
+                        .clone()
+                        .unwrap_or_else(|| "No README".to_string()),
+                )
+                .style(Style::default().fg(GRAY.c300))
+                .wrap(Wrap { trim: false })
+                .scroll((self.readme_scroll, 0))
+                .block(titled_block("README"))
+                .render(layout[2], buf);
+            }
+            Tab::Commits => {
+                let items: Vec<ListItem> = repository
+                    .commits
+                    .iter()
+                    .map(|commit| {
+                        let sha: String = commit
+                            .sha
+                            .as_deref()
+                            .unwrap_or_default()
+                            .chars()
+                            .take(7)
+                            .collect();
+
+                        ListItem::new(Text::from(vec![
+                            Line::from(vec![
+                                Span::styled(
+                                    format!("{:7} ", sha),
+                                    Style::default().fg(YELLOW.c500),
+                                ),
+                                Span::styled(
+                                    commit.message.clone().unwrap_or_default(),
+                                    Style::default().fg(GRAY.c300),
+                                ),
+                            ]),
+                            Line::styled(
+                                format!(
+                                    "        {} {}",
+                                    commit.author.as_deref().unwrap_or_default(),
+                                    commit.date.as_deref().unwrap_or_default()
+                                ),
+                                Style::default().fg(GRAY.c500),
+                            ),
+                        ]))
+                    })
+                    .collect();
+
+                let list = List::new(items)
+                    .block(titled_block("Recent commits"))
+                    .highlight_symbol(">>")
+                    .repeat_highlight_symbol(false);
+
+                StatefulWidget::render(list, layout[2], buf, &mut self.commit_list);
+            }
+            Tab::Issues => {
+                let items: Vec<ListItem> = repository
+                    .issues
+                    .iter()
+                    .map(|issue| {
+                        let state_style = if issue.is_open() {
+                            Style::default().fg(GREEN.c500)
+                        } else {
+                            Style::default().fg(PURPLE.c400)
+                        };
+
+                        let number = issue
+                            .number
+                            .map(|number| format!("#{} ", number))
+                            .unwrap_or_default();
+
+                        let mut details = format!(
+                            "{} {}",
+                            issue.author.as_deref().unwrap_or_default(),
+                            issue.date.as_deref().unwrap_or_default()
+                        );
+
+                        if let Some(comments) = issue.comments {
+                            details.push_str(&format!(", {} comments", comments));
+                        }
+
+                        ListItem::new(Text::from(vec![
+                            Line::from(vec![
+                                Span::styled(
+                                    format!("{:8}", issue.state.as_deref().unwrap_or("open")),
+                                    state_style,
+                                ),
+                                Span::styled(number, Style::default().fg(GRAY.c500)),
+                                Span::styled(
+                                    issue.title.clone().unwrap_or_default(),
+                                    Style::default().fg(GRAY.c300),
+                                ),
+                            ]),
+                            Line::styled(
+                                format!("        {}", details),
+                                Style::default().fg(GRAY.c500),
+                            ),
+                        ]))
+                    })
+                    .collect();
+
+                let list = List::new(items)
+                    .block(titled_block("Recent issues"))
+                    .highlight_symbol(">>")
+                    .repeat_highlight_symbol(false);
+
+                StatefulWidget::render(list, layout[2], buf, &mut self.issue_list);
+            }
+        }
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Tab => {
+                self.tab = Tab::ALL[(self.tab.index() + 1) % Tab::ALL.len()];
+            }
+            KeyCode::BackTab => {
+                self.tab = Tab::ALL[(self.tab.index() + Tab::ALL.len() - 1) % Tab::ALL.len()];
+            }
+            KeyCode::Char(ch @ '1'..='4') => {
+                self.tab = Tab::ALL[ch as usize - '1' as usize];
+            }
+            KeyCode::Char('j') => match self.tab {
+                Tab::Files => self.file_list.select_next(),
+                Tab::Readme => self.readme_scroll = self.readme_scroll.saturating_add(1),
+                Tab::Commits => self.commit_list.select_next(),
+                Tab::Issues => self.issue_list.select_next(),
+            },
+            KeyCode::Char('k') => match self.tab {
+                Tab::Files => self.file_list.select_previous(),
+                Tab::Readme => self.readme_scroll = self.readme_scroll.saturating_sub(1),
+                Tab::Commits => self.commit_list.select_previous(),
+                Tab::Issues => self.issue_list.select_previous(),
+            },
+            KeyCode::Char('o') => {
+                let url = self.repository.as_ref()?.owner_url.clone()?;
+                return Some(Action::Navigate(url));
+            }
+            KeyCode::Enter => {
+                return self.open_selected();
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    /// Directories open as repository pages again; files, commits and
+    /// issues are left to detection.
+    fn open_selected(&self) -> Option<Action> {
+        let repository = self.repository.as_ref()?;
+
+        match self.tab {
+            Tab::Files => {
+                let files = repository.sorted_files();
+                let file = files.get(self.file_list.selected()?)?;
+                let url = file.url.clone()?;
+
+                if file.is_directory() {
+                    Some(Action::NavigateAs(url, ContentType::Repository))
+                } else {
+                    Some(Action::Navigate(url))
+                }
+            }
+            Tab::Readme => None,
+            Tab::Commits => {
+                let commit = repository.commits.get(self.commit_list.selected()?)?;
+                commit.url.clone().map(Action::Navigate)
+            }
+            Tab::Issues => {
+                let issue = repository.issues.get(self.issue_list.selected()?)?;
+                issue.url.clone().map(Action::Navigate)
+            }
+        }
+    }
+}
+
+fn titled_block(title: &str) -> Block<'static> {
+    Block::bordered()
+        .title(format!(" {} ", title))
+        .border_set(border::ROUNDED)
+        .border_style(Style::default().fg(GRAY.c600))
+}
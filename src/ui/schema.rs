@@ -0,0 +1,360 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY},
+    },
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, ListState, Paragraph, StatefulWidget, Widget, Wrap},
+};
+use serde_json::{Map, Value};
+
+use crate::content::custom::CustomContent;
+use crate::prelude::*;
+
+/// A panel built from one part of the translated data.
+enum Section {
+    Fields {
+        title: String,
+        fields: Vec<(String, String)>,
+    },
+    List {
+        title: String,
+        items: Vec<Value>,
+        item_schema: Value,
+        state: ListState,
+    },
+}
+
+/// Generic view for user-defined content types, laid out from the JSON
+/// Schema: objects become key/value panels and arrays become lists.
+pub struct SchemaApp {
+    sections: Vec<Section>,
+    selected_section: usize,
+}
+
+impl SchemaApp {
+    pub fn new() -> Self {
+        Self {
+            sections: Vec::new(),
+            selected_section: 0,
+        }
+    }
+
+    pub fn run(&mut self, content: CustomContent) {
+        self.sections = build_sections(&content.content_type.schema, &content.data);
+        self.selected_section = self
+            .sections
+            .iter()
+            .position(|section| matches!(section, Section::List { .. }))
+            .unwrap_or(0);
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        if self.sections.is_empty() {
+            Paragraph::new("No content").render(area, buf);
+            return;
+        }
+
+        let constraints: Vec<Constraint> = self
+            .sections
+            .iter()
+            .map(|section| match section {
+                Section::Fields { fields, .. } => Constraint::Length(fields.len() as u16 + 2),
+                Section::List { .. } => Constraint::Min(5),
+            })
+            .collect();
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(area);
+
+        for (index, section) in self.sections.iter_mut().enumerate() {
+            let is_selected = index == self.selected_section;
+            let border_style = if is_selected {
+                Style::default().fg(BLUE.c400)
+            } else {
+                Style::default().fg(GRAY.c600)
+            };
+
+            match section {
+                Section::Fields { title, fields } => {
+                    let block = Block::bordered()
+                        .title(format!(" {} ", title))
+                        .border_set(border::ROUNDED)
+                        .border_style(border_style);
+
+                    Paragraph::new(field_lines(fields))
+                        .wrap(Wrap { trim: true })
+                        .block(block)
+                        .render(layout[index], buf);
+                }
+                Section::List {
+                    title,
+                    items,
+                    item_schema,
+                    state,
+                } => {
+                    let columns = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints(vec![Constraint::Percentage(60), Constraint::Percentage(40)])
+                        .split(layout[index]);
+
+                    let block = Block::bordered()
+                        .title(format!(" {} ({}) ", title, items.len()))
+                        .border_set(border::ROUNDED)
+                        .border_style(border_style);
+
+                    let list_items: Vec<ListItem> = items
+                        .iter()
+                        .map(|item| ListItem::new(Line::raw(summarize(item_schema, item))))
+                        .collect();
+
+                    let list = List::new(list_items)
+                        .block(block)
+                        .highlight_symbol(">>")
+                        .highlight_style(Style::default().bold());
+
+                    StatefulWidget::render(list, columns[0], buf, state);
+
+                    let details = state
+                        .selected()
+                        .and_then(|selected| items.get(selected))
+                        .map(|item| flatten_fields(item_schema, item, ""))
+                        .unwrap_or_default();
+
+                    Paragraph::new(field_lines(&details))
+                        .wrap(Wrap { trim: true })
+                        .block(
+                            Block::bordered()
+                                .border_set(border::ROUNDED)
+                                .border_style(Style::default().fg(GRAY.c600)),
+                        )
+                        .render(columns[1], buf);
+                }
+            }
+        }
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Tab | KeyCode::Char('l') => self.select_section(1),
+            KeyCode::BackTab | KeyCode::Char('h') => self.select_section(-1),
+            KeyCode::Char('j') => {
+                if let Some(Section::List { state, .. }) =
+                    self.sections.get_mut(self.selected_section)
+                {
+                    state.select_next();
+                }
+            }
+            KeyCode::Char('k') => {
+                if let Some(Section::List { state, .. }) =
+                    self.sections.get_mut(self.selected_section)
+                {
+                    state.select_previous();
+                }
+            }
+            KeyCode::Enter => return self.open_selected(),
+            _ => {}
+        }
+
+        None
+    }
+
+    fn select_section(&mut self, offset: isize) {
+        if self.sections.is_empty() {
+            return;
+        }
+
+        let count = self.sections.len() as isize;
+        self.selected_section =
+            (self.selected_section as isize + offset).rem_euclid(count) as usize;
+    }
+
+    fn open_selected(&self) -> Option<Action> {
+        let Some(Section::List { items, state, .. }) = self.sections.get(self.selected_section)
+        else {
+            return None;
+        };
+
+        let item = items.get(state.selected()?)?;
+
+        find_url(item).map(Action::OpenUsingRenderingEngine)
+    }
+}
+
+fn build_sections(schema: &Value, data: &Value) -> Vec<Section> {
+    let mut top_fields = Vec::new();
+    let mut sections = Vec::new();
+
+    match data {
+        Value::Object(obj) => {
+            for key in ordered_keys(schema, obj) {
+                let value = &obj[&key];
+                let property_schema = property_schema(schema, &key);
+                let title = label(&property_schema, &key);
+
+                match value {
+                    Value::Array(items) if items.iter().any(Value::is_object) => {
+                        sections.push(Section::List {
+                            title,
+                            items: items.clone(),
+                            item_schema: property_schema.get("items").cloned().unwrap_or_default(),
+                            state: ListState::default().with_selected(Some(0)),
+                        });
+                    }
+                    Value::Object(_) => {
+                        sections.push(Section::Fields {
+                            title,
+                            fields: flatten_fields(&property_schema, value, ""),
+                        });
+                    }
+                    Value::Null => {}
+                    value => top_fields.push((title, to_display_string(value))),
+                }
+            }
+        }
+        Value::Array(items) => {
+            sections.push(Section::List {
+                title: label(schema, "Items"),
+                items: items.clone(),
+                item_schema: schema.get("items").cloned().unwrap_or_default(),
+                state: ListState::default().with_selected(Some(0)),
+            });
+        }
+        value => top_fields.push((label(schema, "Value"), to_display_string(value))),
+    }
+
+    if !top_fields.is_empty() {
+        sections.insert(
+            0,
+            Section::Fields {
+                title: label(schema, "Details"),
+                fields: top_fields,
+            },
+        );
+    }
+
+    sections
+}
+
+/// Keys in the order the schema declares them, followed by any others.
+fn ordered_keys(schema: &Value, obj: &Map<String, Value>) -> Vec<String> {
+    let mut keys: Vec<String> = schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| {
+            properties
+                .keys()
+                .filter(|key| obj.contains_key(*key))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    for key in obj.keys() {
+        if !keys.contains(key) {
+            keys.push(key.clone());
+        }
+    }
+
+    keys
+}
+
+fn property_schema(schema: &Value, key: &str) -> Value {
+    schema
+        .get("properties")
+        .and_then(|properties| properties.get(key))
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn label(schema: &Value, fallback: &str) -> String {
+    schema
+        .get("title")
+        .and_then(Value::as_str)
+        .unwrap_or(fallback)
+        .to_string()
+}
+
+fn flatten_fields(schema: &Value, value: &Value, prefix: &str) -> Vec<(String, String)> {
+    let Value::Object(obj) = value else {
+        return vec![(label(schema, "value"), to_display_string(value))];
+    };
+
+    let mut fields = Vec::new();
+
+    for key in ordered_keys(schema, obj) {
+        let property_schema = property_schema(schema, &key);
+        let name = format!("{}{}", prefix, label(&property_schema, &key));
+
+        match &obj[&key] {
+            Value::Object(_) => {
+                fields.extend(flatten_fields(
+                    &property_schema,
+                    &obj[&key],
+                    &format!("{}.", name),
+                ));
+            }
+            Value::Null => {}
+            value => fields.push((name, to_display_string(value))),
+        }
+    }
+
+    fields
+}
+
+fn summarize(schema: &Value, item: &Value) -> String {
+    let Value::Object(obj) = item else {
+        return to_display_string(item);
+    };
+
+    ordered_keys(schema, obj)
+        .iter()
+        .filter_map(|key| match &obj[key] {
+            Value::String(text) if !text.is_empty() => Some(text.clone()),
+            Value::Number(number) => Some(number.to_string()),
+            Value::Bool(flag) => Some(flag.to_string()),
+            _ => None,
+        })
+        .collect::<Vec<String>>()
+        .join(" · ")
+}
+
+fn field_lines(fields: &[(String, String)]) -> Vec<Line<'static>> {
+    fields
+        .iter()
+        .map(|(key, value)| {
+            Line::from(vec![
+                Span::styled(format!("{}: ", key), Style::default().fg(GRAY.c400)),
+                Span::raw(value.clone()),
+            ])
+        })
+        .collect()
+}
+
+fn to_display_string(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Array(items) if items.iter().all(|item| !item.is_object()) => items
+            .iter()
+            .map(to_display_string)
+            .collect::<Vec<String>>()
+            .join(", "),
+        Value::Array(items) => format!("{} items", items.len()),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+fn find_url(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) if is_valid_url(text) => Some(text.clone()),
+        Value::Object(obj) => obj.values().find_map(find_url),
+        _ => None,
+    }
+}
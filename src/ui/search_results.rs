@@ -0,0 +1,283 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY, GREEN, YELLOW},
+    },
+    text::{Line, Span, Text},
+    widgets::{List, ListItem, ListState, Paragraph, StatefulWidget, Widget, Wrap},
+};
+
+use crate::content::paginated::Paginated;
+use crate::content::search_results::SearchResults;
+use crate::prelude::*;
+use crate::ui::pager::Pager;
+
+#[derive(PartialEq)]
+enum Focus {
+    Results,
+    RelatedQueries,
+}
+
+pub struct SearchResultsApp {
+    search_results: Option<SearchResults>,
+    result_list: ListState,
+    related_list: ListState,
+    focus: Focus,
+    pager: Pager,
+}
+
+impl SearchResultsApp {
+    pub fn new() -> Self {
+        Self {
+            search_results: None,
+            result_list: ListState::default(),
+            related_list: ListState::default(),
+            focus: Focus::Results,
+            pager: Pager::new(),
+        }
+    }
+
+    pub fn run(&mut self, search_results: SearchResults) {
+        self.pager.reset();
+        self.focus = Focus::Results;
+        self.result_list.select(Some(0));
+        self.related_list.select(None);
+        self.search_results = Some(search_results);
+    }
+
+    pub fn append(&mut self, page: SearchResults) {
+        self.pager.reset();
+
+        if let Some(search_results) = &mut self.search_results {
+            search_results.append_page(page);
+        }
+    }
+
+    pub fn next_page_failed(&mut self) {
+        self.pager.reset();
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(search_results) = &self.search_results else {
+            return;
+        };
+
+        let related_height = if search_results.related_queries.is_empty() {
+            0
+        } else {
+            (search_results.related_queries.len() as u16 + 1).min(area.height / 4)
+        };
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(2),
+                Constraint::Min(3),
+                Constraint::Length(related_height),
+            ])
+            .split(area);
+
+        let mut summary = vec![Span::styled(
+            format!(
+                "Results for \"{}\"",
+                search_results.query.as_deref().unwrap_or_default()
+            ),
+            Style::default().fg(GRAY.c300).bold(),
+        )];
+
+        if let Some(result_count) = &search_results.result_count {
+            summary.push(Span::styled(
+                format!("  {}", result_count),
+                Style::default().fg(GRAY.c500),
+            ));
+        }
+
+        Paragraph::new(Line::from(summary)).render(layout[0], buf);
+
+        let terms = search_results.query_terms();
+
+        let mut items: Vec<ListItem> = search_results
+            .results
+            .iter()
+            .map(|result| {
+                let title = result
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| "Untitled".to_string());
+
+                let displayed_url = result
+                    .displayed_url
+                    .clone()
+                    .or_else(|| result.url.as_deref().map(minimize_url))
+                    .unwrap_or_default();
+
+                let mut lines = vec![
+                    Line::styled(title, Style::default().fg(BLUE.c400).bold()),
+                    Line::styled(displayed_url, Style::default().fg(GREEN.c500)),
+                ];
+
+                if let Some(snippet) = &result.snippet {
+                    lines.push(highlight_terms(snippet, &terms));
+                }
+
+                lines.push(Line::from(""));
+
+                ListItem::new(Text::from(lines))
+            })
+            .collect();
+
+        if let Some(status) = self.pager.status(search_results.next_page_url.as_deref()) {
+            items.push(ListItem::new(Line::styled(
+                status,
+                Style::default().fg(GRAY.c500),
+            )));
+        }
+
+        let list = List::new(items)
+            .highlight_symbol(">>")
+            .repeat_highlight_symbol(false);
+
+        StatefulWidget::render(list, layout[1], buf, &mut self.result_list);
+
+        if related_height > 0 {
+            let related_layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Length(1), Constraint::Min(0)])
+                .split(layout[2]);
+
+            let heading_style = if self.focus == Focus::RelatedQueries {
+                Style::default().fg(GRAY.c300).bold()
+            } else {
+                Style::default().fg(GRAY.c500)
+            };
+
+            Paragraph::new(Line::styled("Related searches (Tab)", heading_style))
+                .wrap(Wrap { trim: true })
+                .render(related_layout[0], buf);
+
+            let related_items: Vec<ListItem> = search_results
+                .related_queries
+                .iter()
+                .map(|related_query| {
+                    ListItem::new(Line::styled(
+                        related_query.query.clone().unwrap_or_default(),
+                        Style::default().fg(BLUE.c500),
+                    ))
+                })
+                .collect();
+
+            let related_list = List::new(related_items).highlight_symbol(">>");
+
+            StatefulWidget::render(related_list, related_layout[1], buf, &mut self.related_list);
+        }
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Char('j') => {
+                return self.select_next();
+            }
+            KeyCode::Char('k') => {
+                self.selected_list().select_previous();
+            }
+            KeyCode::Char('n') => {
+                return self.load_next_page();
+            }
+            KeyCode::Char('p') => {
+                let url = self.search_results.as_ref()?.previous_page_url.clone()?;
+                return Some(Action::Navigate(url));
+            }
+            KeyCode::Tab => {
+                self.toggle_focus();
+            }
+            KeyCode::Enter => {
+                return self.open_selected();
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn selected_list(&mut self) -> &mut ListState {
+        match self.focus {
+            Focus::Results => &mut self.result_list,
+            Focus::RelatedQueries => &mut self.related_list,
+        }
+    }
+
+    fn toggle_focus(&mut self) {
+        let has_related_queries = self
+            .search_results
+            .as_ref()
+            .is_some_and(|search_results| !search_results.related_queries.is_empty());
+
+        if self.focus == Focus::Results && has_related_queries {
+            self.focus = Focus::RelatedQueries;
+            if self.related_list.selected().is_none() {
+                self.related_list.select(Some(0));
+            }
+        } else {
+            self.focus = Focus::Results;
+        }
+    }
+
+    fn select_next(&mut self) -> Option<Action> {
+        if self.focus != Focus::Results {
+            self.related_list.select_next();
+            return None;
+        }
+
+        let search_results = self.search_results.as_ref()?;
+        self.pager.select_next(
+            &mut self.result_list,
+            search_results.results.len(),
+            search_results.next_page_url.as_deref(),
+        )
+    }
+
+    fn load_next_page(&mut self) -> Option<Action> {
+        let next_page_url = self.search_results.as_ref()?.next_page_url.as_deref();
+        self.pager.load_next_page(next_page_url)
+    }
+
+    fn open_selected(&self) -> Option<Action> {
+        let search_results = self.search_results.as_ref()?;
+
+        let url = match self.focus {
+            Focus::Results => search_results
+                .results
+                .get(self.result_list.selected()?)?
+                .url
+                .clone(),
+            Focus::RelatedQueries => search_results
+                .related_queries
+                .get(self.related_list.selected()?)?
+                .url
+                .clone(),
+        };
+
+        url.map(Action::Navigate)
+    }
+}
+
+/// Splits `text` into spans, emphasising words that contain a query term.
+fn highlight_terms(text: &str, terms: &[String]) -> Line<'static> {
+    let spans: Vec<Span> = text
+        .split_inclusive(char::is_whitespace)
+        .map(|word| {
+            let normalized = word.to_lowercase();
+
+            if terms.iter().any(|term| normalized.contains(term.as_str())) {
+                Span::styled(word.to_string(), Style::default().fg(YELLOW.c300).bold())
+            } else {
+                Span::styled(word.to_string(), Style::default().fg(GRAY.c400))
+            }
+        })
+        .collect();
+
+    Line::from(spans)
+}
@@ -0,0 +1,334 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{
+        Style,
+        palette::tailwind::{BLUE, GRAY},
+    },
+    text::{Line, Span},
+    widgets::{Cell, Paragraph, Row, StatefulWidget, Table as TableWidget, TableState, Widget},
+};
+
+use crate::content::table::Table;
+use crate::export::write_export;
+use crate::prelude::*;
+
+const MIN_COLUMN_WIDTH: u16 = 3;
+const MAX_INITIAL_COLUMN_WIDTH: u16 = 40;
+const COLUMN_SPACING: u16 = 1;
+const HIGHLIGHT_SYMBOL: &str = ">>";
+
+pub struct TableApp {
+    table: Option<Table>,
+    /// Column sorted on, and whether the order is descending.
+    sort: Option<(usize, bool)>,
+    sorted_indices: Vec<usize>,
+    widths: Vec<u16>,
+    column: usize,
+    /// First column shown, for tables wider than the screen.
+    first_column: usize,
+    state: TableState,
+}
+
+impl TableApp {
+    pub fn new() -> Self {
+        Self {
+            table: None,
+            sort: None,
+            sorted_indices: Vec::new(),
+            widths: Vec::new(),
+            column: 0,
+            first_column: 0,
+            state: TableState::default(),
+        }
+    }
+
+    pub fn run(&mut self, table: Table) {
+        self.widths = initial_widths(&table);
+        self.sort = None;
+        self.column = 0;
+        self.first_column = 0;
+        self.table = Some(table);
+        self.update_order();
+        self.state.select(Some(0));
+    }
+
+    fn update_order(&mut self) {
+        let Some(table) = &self.table else {
+            self.sorted_indices.clear();
+            return;
+        };
+
+        self.sorted_indices = match self.sort {
+            Some((column, descending)) => table.sorted_indices(column, descending),
+            None => (0..table.rows.len()).collect(),
+        };
+    }
+
+    /// Index of the selected row in the table, as opposed to its position
+    /// in the current order.
+    fn selected_row(&self) -> Option<usize> {
+        self.sorted_indices.get(self.state.selected()?).copied()
+    }
+
+    /// Keeps the selected column on screen, scrolling horizontally as
+    /// needed, and returns the columns that fit in `width`.
+    fn visible_columns(&mut self, width: u16) -> Vec<usize> {
+        let width = width.saturating_sub(HIGHLIGHT_SYMBOL.len() as u16);
+        let span = |columns: &[u16]| -> u16 {
+            columns
+                .iter()
+                .map(|width| width + COLUMN_SPACING)
+                .sum::<u16>()
+                .saturating_sub(COLUMN_SPACING)
+        };
+
+        self.first_column = self.first_column.min(self.column);
+
+        while self.first_column < self.column
+            && span(&self.widths[self.first_column..=self.column]) > width
+        {
+            self.first_column += 1;
+        }
+
+        let mut visible = Vec::new();
+        let mut used = 0;
+
+        for column in self.first_column..self.widths.len() {
+            let needed = used
+                + self.widths[column]
+                + if visible.is_empty() {
+                    0
+                } else {
+                    COLUMN_SPACING
+                };
+
+            if needed > width && !visible.is_empty() {
+                break;
+            }
+
+            visible.push(column);
+            used = needed;
+        }
+
+        visible
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        if self.table.is_none() {
+            return;
+        }
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(1),
+                Constraint::Min(3),
+                Constraint::Length(1),
+            ])
+            .split(area);
+
+        let visible = self.visible_columns(layout[1].width);
+        let Some(table) = &self.table else {
+            return;
+        };
+
+        let mut caption = vec![Span::styled(
+            table.caption.clone().unwrap_or_default(),
+            Style::default().fg(GRAY.c300).bold(),
+        )];
+
+        caption.push(Span::styled(
+            format!("  {} rows", table.rows.len()),
+            Style::default().fg(GRAY.c500),
+        ));
+
+        if visible.len() < self.widths.len() {
+            caption.push(Span::styled(
+                format!(
+                    ", columns {}-{} of {}",
+                    visible.first().map_or(0, |column| column + 1),
+                    visible.last().map_or(0, |column| column + 1),
+                    self.widths.len()
+                ),
+                Style::default().fg(GRAY.c500),
+            ));
+        }
+
+        Paragraph::new(Line::from(caption)).render(layout[0], buf);
+
+        let header = Row::new(visible.iter().map(|column| {
+            let name = table.columns.get(*column).cloned().unwrap_or_default();
+
+            let indicator = match self.sort {
+                Some((sorted, false)) if sorted == *column => " ▲",
+                Some((sorted, true)) if sorted == *column => " ▼",
+                _ => "",
+            };
+
+            let style = if *column == self.column {
+                Style::default().fg(BLUE.c300).bold().underlined()
+            } else {
+                Style::default().fg(BLUE.c400).bold()
+            };
+
+            Cell::from(format!("{}{}", name, indicator)).style(style)
+        }));
+
+        let rows: Vec<Row> = self
+            .sorted_indices
+            .iter()
+            .map(|index| {
+                let row = &table.rows[*index];
+
+                Row::new(visible.iter().map(|column| {
+                    match row.cells.get(*column) {
+                        Some(cell) if cell.url.is_some() => {
+                            Cell::from(cell.text.clone().unwrap_or_default())
+                                .style(Style::default().fg(BLUE.c400).underlined())
+                        }
+                        Some(cell) => Cell::from(cell.text.clone().unwrap_or_default())
+                            .style(Style::default().fg(GRAY.c300)),
+                        None => Cell::default(),
+                    }
+                }))
+            })
+            .collect();
+
+        let widths: Vec<Constraint> = visible
+            .iter()
+            .map(|column| Constraint::Length(self.widths[*column]))
+            .collect();
+
+        let widget = TableWidget::new(rows, widths)
+            .header(header)
+            .column_spacing(COLUMN_SPACING)
+            .highlight_symbol(HIGHLIGHT_SYMBOL)
+            .row_highlight_style(Style::default().bold())
+            .cell_highlight_style(Style::default().reversed());
+
+        self.state
+            .select_column(visible.iter().position(|column| *column == self.column));
+
+        StatefulWidget::render(widget, layout[1], buf, &mut self.state);
+
+        Paragraph::new(Line::styled(
+            "s to sort, < and > to resize, x to export as .csv, X as .tsv",
+            Style::default().fg(GRAY.c500),
+        ))
+        .render(layout[2], buf);
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Char('j') => {
+                self.state.select_next();
+            }
+            KeyCode::Char('k') => {
+                self.state.select_previous();
+            }
+            KeyCode::Char('l') => {
+                self.column = (self.column + 1).min(self.widths.len().saturating_sub(1));
+            }
+            KeyCode::Char('h') => {
+                self.column = self.column.saturating_sub(1);
+            }
+            KeyCode::Char('s') => {
+                self.toggle_sort();
+            }
+            KeyCode::Char('>') | KeyCode::Char('+') => {
+                self.resize_column(2);
+            }
+            KeyCode::Char('<') | KeyCode::Char('-') => {
+                self.resize_column(-2);
+            }
+            KeyCode::Char('x') => {
+                return Some(self.export("csv", ','));
+            }
+            KeyCode::Char('X') => {
+                return Some(self.export("tsv", '\t'));
+            }
+            KeyCode::Enter => {
+                return self.selected_url().map(Action::Navigate);
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    /// Sorts on the selected column, ascending then descending, and back to
+    /// the order of the page.
+    fn toggle_sort(&mut self) {
+        let selected_row = self.selected_row();
+
+        self.sort = match self.sort {
+            Some((column, false)) if column == self.column => Some((column, true)),
+            Some((column, true)) if column == self.column => None,
+            _ => Some((self.column, false)),
+        };
+
+        self.update_order();
+
+        let position =
+            selected_row.and_then(|row| self.sorted_indices.iter().position(|index| *index == row));
+        self.state.select(position.or(Some(0)));
+    }
+
+    fn resize_column(&mut self, delta: i16) {
+        if let Some(width) = self.widths.get_mut(self.column) {
+            *width = width.saturating_add_signed(delta).max(MIN_COLUMN_WIDTH);
+        }
+    }
+
+    /// Link of the selected cell, or the first link in the selected row.
+    fn selected_url(&self) -> Option<String> {
+        let row = &self.table.as_ref()?.rows[self.selected_row()?];
+
+        row.cells
+            .get(self.column)
+            .and_then(|cell| cell.url.clone())
+            .or_else(|| row.cells.iter().find_map(|cell| cell.url.clone()))
+    }
+
+    /// Exports all rows in the order shown.
+    fn export(&self, extension: &str, delimiter: char) -> Action {
+        let Some(table) = &self.table else {
+            return Action::ShowNotice("Nothing to export".to_string());
+        };
+
+        let name = table.caption.clone().unwrap_or_else(|| "table".to_string());
+        let contents = table.to_delimited(&self.sorted_indices, delimiter);
+
+        match write_export(&name, extension, &contents) {
+            Ok(path) => Action::ShowNotice(format!("Exported to {}", path.display())),
+            Err(e) => {
+                log::error!("Could not export table: {:?}", e);
+                Action::ShowNotice("Could not export table".to_string())
+            }
+        }
+    }
+}
+
+/// Widths that fit the longest header or cell of each column, up to a limit.
+fn initial_widths(table: &Table) -> Vec<u16> {
+    (0..table.column_count())
+        .map(|column| {
+            let header = table
+                .columns
+                .get(column)
+                .map_or(0, |name| name.chars().count() + 2);
+
+            let longest = table
+                .rows
+                .iter()
+                .map(|row| row.cell_text(column).chars().count())
+                .chain(std::iter::once(header))
+                .max()
+                .unwrap_or(0);
+
+            (longest.min(MAX_INITIAL_COLUMN_WIDTH as usize) as u16).max(MIN_COLUMN_WIDTH)
+        })
+        .collect()
+}
@@ -0,0 +1,27 @@
+/// Greedy word wrap, for text whose height must be known before rendering.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+
+        for word in paragraph.split_whitespace() {
+            let line_length = line.chars().count();
+
+            if line_length > 0 && line_length + 1 + word.chars().count() > width {
+                lines.push(std::mem::take(&mut line));
+            }
+
+            if !line.is_empty() {
+                line.push(' ');
+            }
+
+            line.push_str(word);
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
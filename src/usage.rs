@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use crate::prelude::*;
+use crate::storage;
+
+const USAGE_FILE_NAME: &str = "usage.json";
+
+static LEDGER_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct BudgetSettings {
+    /// Tokens a single page translation may use.
+    pub page_tokens: Option<u64>,
+    /// Tokens that may be used per calendar day across all pages.
+    pub daily_tokens: Option<u64>,
+    pub on_exceeded: BudgetAction,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetAction {
+    #[default]
+    Abort,
+    Confirm,
+}
+
+impl BudgetSettings {
+    /// Tokens the next translation may use, or `None` if unlimited.
+    pub fn remaining_tokens(&self, used_today: u64) -> Option<u64> {
+        let daily_remaining = self
+            .daily_tokens
+            .map(|daily| daily.saturating_sub(used_today));
+
+        match (self.page_tokens, daily_remaining) {
+            (Some(page), Some(daily)) => Some(page.min(daily)),
+            (page, daily) => page.or(daily),
+        }
+    }
+}
+
+/// Tokens used on one domain on one day.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UsageRecord {
+    pub date: String,
+    pub domain: String,
+    pub tokens: u64,
+}
+
+pub fn record_usage(url: &str, tokens: u64) {
+    if tokens == 0 {
+        return;
+    }
+
+    let _guard = LEDGER_LOCK.lock().unwrap();
+
+    let date = today();
+    let domain = minimize_url(url);
+    let mut records: Vec<UsageRecord> = storage::load(USAGE_FILE_NAME);
+
+    match records
+        .iter_mut()
+        .find(|record| record.date == date && record.domain == domain)
+    {
+        Some(record) => record.tokens += tokens,
+        None => records.push(UsageRecord {
+            date,
+            domain,
+            tokens,
+        }),
+    }
+
+    if let Err(e) = storage::save(USAGE_FILE_NAME, &records) {
+        log::warn!("Could not save token usage: {:?}", e);
+    }
+}
+
+pub fn tokens_used_today() -> u64 {
+    let _guard = LEDGER_LOCK.lock().unwrap();
+
+    let date = today();
+    let records: Vec<UsageRecord> = storage::load(USAGE_FILE_NAME);
+
+    records
+        .iter()
+        .filter(|record| record.date == date)
+        .map(|record| record.tokens)
+        .sum()
+}
+
+pub fn print_summary() {
+    let records: Vec<UsageRecord> = storage::load(USAGE_FILE_NAME);
+
+    if records.is_empty() {
+        println!("No token usage recorded");
+        return;
+    }
+
+    let mut by_day: BTreeMap<&str, u64> = BTreeMap::new();
+    let mut by_domain: BTreeMap<&str, u64> = BTreeMap::new();
+
+    for record in &records {
+        *by_day.entry(&record.date).or_insert(0) += record.tokens;
+        *by_domain.entry(&record.domain).or_insert(0) += record.tokens;
+    }
+
+    println!("Usage by day");
+    for (date, tokens) in &by_day {
+        println!("  {:<40} {:>12}", date, tokens);
+    }
+
+    println!();
+    println!("Usage by domain");
+    for (domain, tokens) in &by_domain {
+        println!("  {:<40} {:>12}", domain, tokens);
+    }
+
+    println!();
+    println!("  {:<40} {:>12}", "Total", by_day.values().sum::<u64>());
+}
+
+fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
@@ -1,3 +1,8 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::constants::{DEFAULT_SEARCH_KEYWORDS, PROGRAM_NAME, SEARCH_KEYWORD_PLACEHOLDER};
+
 pub fn is_valid_url(url: &str) -> bool {
     let Some(rest) = url
         .strip_prefix("https://")
@@ -9,6 +14,88 @@ pub fn is_valid_url(url: &str) -> bool {
     !host.is_empty() && host.contains('.')
 }
 
+/// Turns navigation input into a URL, expanding search keywords such as
+/// `ddg some query` and adding a scheme to bare domains.
+pub fn expand_navigation_input(
+    input: &str,
+    search_keywords: &HashMap<String, String>,
+) -> Option<String> {
+    let input = input.trim();
+
+    if is_valid_url(input) {
+        return Some(input.to_string());
+    }
+
+    let (keyword, query) = match input.split_once(char::is_whitespace) {
+        Some((keyword, query)) => (keyword, query.trim()),
+        None => (input, ""),
+    };
+
+    let template = search_keywords
+        .get(keyword)
+        .map(String::as_str)
+        .or_else(|| {
+            DEFAULT_SEARCH_KEYWORDS
+                .iter()
+                .find(|(name, _)| *name == keyword)
+                .map(|(_, template)| *template)
+        });
+
+    if let Some(template) = template {
+        let url = template.replace(SEARCH_KEYWORD_PLACEHOLDER, &percent_encode(query));
+        return is_valid_url(&url).then_some(url);
+    }
+
+    if !input.contains(char::is_whitespace) {
+        let url = format!("https://{}", input);
+        if is_valid_url(&url) {
+            return Some(url);
+        }
+    }
+
+    None
+}
+
+pub fn percent_encode(input: &str) -> String {
+    input
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Resolves a possibly relative link found on the page at `base_url`.
+pub fn resolve_url(base_url: &str, href: &str) -> String {
+    let href = href.trim();
+
+    if is_valid_url(href) {
+        return href.to_string();
+    }
+
+    let (scheme, rest) = base_url.split_once("://").unwrap_or(("https", base_url));
+    let origin_end = rest.find('/').unwrap_or(rest.len());
+    let origin = format!("{}://{}", scheme, &rest[..origin_end]);
+    let path = rest[origin_end..]
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default();
+
+    if let Some(protocol_relative) = href.strip_prefix("//") {
+        format!("{}://{}", scheme, protocol_relative)
+    } else if href.starts_with('/') {
+        format!("{}{}", origin, href)
+    } else if href.starts_with('?') || href.starts_with('#') {
+        format!("{}{}{}", origin, path, href)
+    } else {
+        let directory = path.rsplit_once('/').map_or("", |(directory, _)| directory);
+        format!("{}{}/{}", origin, directory, href)
+    }
+}
+
 pub fn minimize_url(full_url: &str) -> String {
     full_url
         .split('/')
@@ -17,6 +104,21 @@ pub fn minimize_url(full_url: &str) -> String {
         .unwrap_or_else(|| full_url.to_string())
 }
 
+/// Looks up the entry for the URL's host, falling back to its parent domains.
+pub fn find_domain_entry<'a, T>(entries: &'a HashMap<String, T>, url: &str) -> Option<&'a T> {
+    let host = minimize_url(url);
+    let host = host.split(':').next().unwrap_or_default();
+
+    let mut domain = host;
+    loop {
+        if let Some(entry) = entries.get(domain) {
+            return Some(entry);
+        }
+
+        domain = domain.split_once('.')?.1;
+    }
+}
+
 pub fn to_safe_dir_name(input: &str) -> String {
     let mut safe_name: String = input
         .chars()
@@ -43,3 +145,104 @@ pub fn to_safe_dir_name(input: &str) -> String {
 
     safe_name
 }
+
+pub fn get_data_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(PROGRAM_NAME))
+}
+
+pub fn get_config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(PROGRAM_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keywords(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(keyword, template)| (keyword.to_string(), template.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn expands_search_keywords() {
+        let none = HashMap::new();
+
+        assert_eq!(
+            expand_navigation_input("ddg rust tui", &none).as_deref(),
+            Some("https://duckduckgo.com/html/?q=rust%20tui")
+        );
+        assert_eq!(
+            expand_navigation_input("hn", &none).as_deref(),
+            Some("https://news.ycombinator.com")
+        );
+    }
+
+    #[test]
+    fn configured_keywords_take_precedence() {
+        let custom = keywords(&[("ddg", "https://search.example.com/?q={}")]);
+
+        assert_eq!(
+            expand_navigation_input("  ddg  query ", &custom).as_deref(),
+            Some("https://search.example.com/?q=query")
+        );
+    }
+
+    #[test]
+    fn adds_a_scheme_to_bare_domains() {
+        let none = HashMap::new();
+
+        assert_eq!(
+            expand_navigation_input("example.com/path", &none).as_deref(),
+            Some("https://example.com/path")
+        );
+        assert_eq!(
+            expand_navigation_input("http://example.com", &none).as_deref(),
+            Some("http://example.com")
+        );
+        assert_eq!(expand_navigation_input("localhost", &none), None);
+        assert_eq!(expand_navigation_input("two words", &none), None);
+    }
+
+    #[test]
+    fn percent_encodes_non_ascii_and_reserved_characters() {
+        assert_eq!(percent_encode("a-b_c.d~e"), "a-b_c.d~e");
+        assert_eq!(percent_encode("a&b=c/d"), "a%26b%3Dc%2Fd");
+        assert_eq!(
+            percent_encode("café 日本"),
+            "caf%C3%A9%20%E6%97%A5%E6%9C%AC"
+        );
+    }
+
+    #[test]
+    fn resolves_relative_urls() {
+        let base = "https://example.com/blog/post.html?page=2#top";
+
+        assert_eq!(
+            resolve_url(base, "https://other.org/a"),
+            "https://other.org/a"
+        );
+        assert_eq!(
+            resolve_url(base, "//cdn.example.com/x.png"),
+            "https://cdn.example.com/x.png"
+        );
+        assert_eq!(resolve_url(base, "/about"), "https://example.com/about");
+        assert_eq!(
+            resolve_url(base, "?page=3"),
+            "https://example.com/blog/post.html?page=3"
+        );
+        assert_eq!(
+            resolve_url(base, "#comments"),
+            "https://example.com/blog/post.html#comments"
+        );
+        assert_eq!(
+            resolve_url(base, "other.html"),
+            "https://example.com/blog/other.html"
+        );
+        assert_eq!(
+            resolve_url("https://example.com", "item?id=1"),
+            "https://example.com/item?id=1"
+        );
+    }
+}
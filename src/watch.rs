@@ -0,0 +1,85 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+const DEFAULT_WATCH_INTERVAL_SECONDS: u64 = 300;
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct WatchSettings {
+    /// Interval used when watching is toggled on for the current page.
+    pub interval_seconds: Option<u64>,
+    /// Pages watched as soon as they are opened, with their intervals in
+    /// seconds.
+    pub pages: HashMap<String, u64>,
+    /// Ring the terminal bell when new entries appear.
+    pub bell: bool,
+    /// Send a desktop notification when new entries appear.
+    pub desktop_notification: bool,
+}
+
+/// Background refresh schedule for the page being viewed.
+#[derive(Clone, Debug)]
+pub struct Watch {
+    pub url: String,
+    pub interval: Duration,
+    pub last_refresh: Instant,
+    pub in_flight: bool,
+}
+
+impl WatchSettings {
+    pub fn default_interval(&self) -> Duration {
+        Duration::from_secs(
+            self.interval_seconds
+                .unwrap_or(DEFAULT_WATCH_INTERVAL_SECONDS),
+        )
+    }
+
+    pub fn watch_for(&self, url: &str) -> Option<Watch> {
+        self.pages
+            .get(url)
+            .map(|&seconds| Watch::new(url.to_string(), Duration::from_secs(seconds)))
+    }
+
+    pub fn notify(&self, message: &str) {
+        if self.bell {
+            print!("\x07");
+            let _ = io::stdout().flush();
+        }
+
+        if self.desktop_notification {
+            let result = if cfg!(target_os = "macos") {
+                Command::new("osascript")
+                    .arg("-e")
+                    .arg(format!(
+                        "display notification {:?} with title \"pori\"",
+                        message
+                    ))
+                    .spawn()
+            } else {
+                Command::new("notify-send").arg("pori").arg(message).spawn()
+            };
+
+            if let Err(e) = result {
+                log::warn!("Could not send desktop notification: {}", e);
+            }
+        }
+    }
+}
+
+impl Watch {
+    pub fn new(url: String, interval: Duration) -> Self {
+        Watch {
+            url,
+            interval,
+            last_refresh: Instant::now(),
+            in_flight: false,
+        }
+    }
+
+    pub fn is_due(&self) -> bool {
+        !self.in_flight && self.last_refresh.elapsed() >= self.interval
+    }
+}